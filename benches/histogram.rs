@@ -0,0 +1,47 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, AxisScale, Criterion, PlotConfiguration,
+};
+use ndarray::prelude::*;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    histogram::{Bins, Edges, Grid},
+    HistogramExt,
+};
+use noisy_float::types::{n64, N64};
+
+fn uniform_edges(n_bins: usize) -> Edges<N64> {
+    let bin_width = 1. / n_bins as f64;
+    Edges::from(
+        (0..=n_bins)
+            .map(|i| n64(i as f64 * bin_width))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn bin_lookup(c: &mut Criterion) {
+    let n_bins = vec![10, 100, 1000, 10000];
+    let mut group = c.benchmark_group("bin_lookup");
+    group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+    let observations = Array::random((100_000, 1), Uniform::new(-0.1, 1.1).unwrap()).mapv(n64);
+    for &n_bins in &n_bins {
+        let binary_search = Grid::from(vec![Bins::new(uniform_edges(n_bins))]);
+        group.bench_with_input(
+            format!("binary_search/{}", n_bins),
+            &binary_search,
+            |b, grid| b.iter(|| black_box(observations.histogram(grid.clone()))),
+        );
+        let uniform = Grid::from(vec![Bins::with_uniform_lookup(uniform_edges(n_bins))]);
+        group.bench_with_input(format!("uniform/{}", n_bins), &uniform, |b, grid| {
+            b.iter(|| black_box(observations.histogram(grid.clone())))
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = bin_lookup
+}
+criterion_main!(benches);
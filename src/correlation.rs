@@ -1,6 +1,12 @@
-use crate::errors::EmptyInput;
+use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
+use crate::MaybeNan;
 use ndarray::prelude::*;
+#[cfg(feature = "rayon")]
+use ndarray::Zip;
 use num_traits::{Float, FromPrimitive};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
 
 /// Extension trait for `ndarray` providing functions
 /// to compute different correlation measures.
@@ -118,6 +124,416 @@ pub trait CorrelationExt<A> {
     where
         A: Float + FromPrimitive;
 
+    /// Return the cross-covariance matrix `C` between two 2-dimensional arrays of
+    /// observations `M` and `N`, with the same layout as [`cov`].
+    ///
+    /// Let `(r1, o)` be the shape of `M` and `(r2, o)` be the shape of `N`: the two arrays hold
+    /// the same `o` observations of two sets of random variables. `C` has shape `(r1, r2)`
+    /// and
+    /// ```text
+    /// C_ij = cov(M_i, N_j)
+    /// ```
+    ///
+    /// `a.cov_with(&a, ddof)` is equal to `a.cov(ddof)`.
+    ///
+    /// Returns
+    /// * `MultiInputError::ShapeMismatch` if `M` and `N` don't have the same number of
+    ///   observations;
+    /// * `MultiInputError::EmptyInput` if there are zero observations.
+    ///
+    /// **Panics** if `ddof` is negative or greater than or equal to the number of
+    /// observations, or if the type cast of `n_observations` from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{aview2, arr2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 2., 3.]]);
+    /// let b = arr2(&[[2., 4., 6.],
+    ///                [3., 2., 1.]]);
+    /// let covariance = a.cov_with(&b, 1.).unwrap();
+    /// assert_eq!(covariance, aview2(&[[2., -1.]]));
+    /// ```
+    ///
+    /// [`cov`]: #tymethod.cov
+    fn cov_with(&self, other: &ArrayRef2<A>, ddof: A) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the cross-correlation matrix `R` of [Pearson correlation coefficients] between
+    /// two 2-dimensional arrays of observations `M` and `N`, with the same layout as
+    /// [`pearson_correlation`].
+    ///
+    /// Let `(r1, o)` be the shape of `M` and `(r2, o)` be the shape of `N`: the two arrays hold
+    /// the same `o` observations of two sets of random variables. `R` has shape `(r1, r2)`
+    /// and
+    /// ```text
+    /// R_ij = rho(M_i, N_j)
+    /// ```
+    ///
+    /// Returns
+    /// * `MultiInputError::ShapeMismatch` if `M` and `N` don't have the same number of
+    ///   observations;
+    /// * `MultiInputError::EmptyInput` if `M` or `N` is empty (either zero observations or
+    ///   zero random variables).
+    ///
+    /// **Panics** if the type cast of `n_observations` from `usize` to `A` fails or
+    /// if the standard deviation of one of the random variables is zero and
+    /// division by zero panics for type A.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{arr2, aview2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 2., 3.]]);
+    /// let b = arr2(&[[2., 4., 6.],
+    ///                [3., 2., 1.]]);
+    /// let corr = a.pearson_correlation_with(&b).unwrap();
+    /// assert_abs_diff_eq!(corr, aview2(&[[1., -1.]]), epsilon = 1e-12);
+    /// ```
+    ///
+    /// [Pearson correlation coefficients]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    fn pearson_correlation_with(&self, other: &ArrayRef2<A>) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the weighted covariance matrix `C` for a 2-dimensional array of observations
+    /// `M`, with the same layout as [`cov`].
+    ///
+    /// Each observation (i.e. each column of `M`) can be given a frequency weight, the number
+    /// of times it was observed, and an analytic weight, representing its reliability. With
+    /// `wᵢ` the product of the weights of the `i`-th observation (missing weights being 1),
+    /// the weighted covariance of two random variables is defined as:
+    ///
+    /// ```text
+    ///                 1    n
+    /// cov(X, Y) = ―――――――  ∑ wᵢ(xᵢ - x̅)(yᵢ - y̅)
+    ///             V₁ - δ  i=1
+    /// ```
+    ///
+    /// where `x̅` and `y̅` are the means weighted by `w`, `V₁ = ∑ wᵢ` and
+    /// ```text
+    ///      ⎧ ddof               without analytic weights
+    /// δ =  ⎨
+    ///      ⎩ ddof ∑ wᵢ aᵢ / V₁   with analytic weights aᵢ
+    /// ```
+    ///
+    /// These are the semantics of the `fweights` and `aweights` arguments of [NumPy's `cov`].
+    /// Without weights, it is equal to [`cov`].
+    ///
+    /// Returns
+    /// * `MultiInputError::ShapeMismatch` if the length of `fweights` or `aweights` is not
+    ///   the number of observations;
+    /// * `MultiInputError::EmptyInput` if there are zero observations.
+    ///
+    /// **Panics** if any weight is negative, if any frequency weight is not a whole number (as
+    /// `NumPy` does), if the degrees of freedom `V₁ - δ` are not strictly positive (e.g. if
+    /// `ddof` is too large or all the weights are zero), or if the type cast of
+    /// `n_observations` from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, aview2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = array![[1., 3., 5.],
+    ///                [2., 4., 6.]];
+    /// // Same as observing the first column twice
+    /// let repeated = array![[1., 1., 3., 5.],
+    ///                       [2., 2., 4., 6.]];
+    /// let covariance = a.weighted_cov(Some(&array![2., 1., 1.]), None, 1.).unwrap();
+    /// assert_eq!(covariance, repeated.cov(1.).unwrap());
+    /// ```
+    ///
+    /// [`cov`]: #tymethod.cov
+    /// [NumPy's `cov`]: https://numpy.org/doc/stable/reference/generated/numpy.cov.html
+    fn weighted_cov(
+        &self,
+        fweights: Option<&ArrayRef1<A>>,
+        aweights: Option<&ArrayRef1<A>>,
+        ddof: A,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the weighted [Pearson correlation coefficients] for a 2-dimensional array of
+    /// observations `M`, with the same layout as [`pearson_correlation`].
+    ///
+    /// The coefficients are computed from the weighted covariance matrix `C` returned by
+    /// [`weighted_cov`], for the same frequency and analytic weights:
+    /// ```text
+    /// R_ij = C_ij / √(C_ii C_jj)
+    /// ```
+    ///
+    /// Returns
+    /// * `MultiInputError::ShapeMismatch` if the length of `fweights` or `aweights` is not
+    ///   the number of observations;
+    /// * `MultiInputError::EmptyInput` if `M` is empty (either zero observations or zero
+    ///   random variables).
+    ///
+    /// **Panics** if any weight is negative, if any frequency weight is not a whole number, if
+    /// all the weights are zero, or if the type cast of `n_observations` from `usize` to `A`
+    /// fails.
+    ///
+    /// [Pearson correlation coefficients]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    /// [`weighted_cov`]: #tymethod.weighted_cov
+    fn weighted_pearson_correlation(
+        &self,
+        fweights: Option<&ArrayRef1<A>>,
+        aweights: Option<&ArrayRef1<A>>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the covariance matrix `C` for a 2-dimensional array of observations `M`, with
+    /// the same layout as [`cov`], skipping NaN values.
+    ///
+    /// Each entry `C_ij` is computed from the pairwise-complete observations of the random
+    /// variables `X_i` and `X_j`, i.e. the observations where neither of them is NaN: their
+    /// number `n_ij` replaces `n` in the definition of [`cov`], and the means are computed
+    /// over the same observations. This is how [pandas' `DataFrame.cov`] handles missing
+    /// values.
+    ///
+    /// `C_ij` is NaN if `n_ij` is smaller than `min_periods` (if any) or not greater than
+    /// `ddof`.
+    ///
+    /// If `M` has zero observations, it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `ddof` is negative, or if the type cast of `n_ij` from `usize` to `A`
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, aview2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = array![[1., 3., 5., f64::NAN],
+    ///                [2., f64::NAN, 6., 4.]];
+    /// // The covariance of the two variables is computed over the first and third observations
+    /// let covariance = a.cov_skipnan(1., None).unwrap();
+    /// assert_eq!(covariance, aview2(&[[4., 8.], [8., 4.]]));
+    /// ```
+    ///
+    /// [`cov`]: #tymethod.cov
+    /// [pandas' `DataFrame.cov`]: https://pandas.pydata.org/docs/reference/api/pandas.DataFrame.cov.html
+    fn cov_skipnan(&self, ddof: A, min_periods: Option<usize>) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Return the [Pearson correlation coefficients] for a 2-dimensional array of
+    /// observations `M`, with the same layout as [`pearson_correlation`], skipping NaN values.
+    ///
+    /// Each coefficient `R_ij` is computed from the pairwise-complete observations of the
+    /// random variables `X_i` and `X_j`, as in [`cov_skipnan`]. It is NaN if their number is
+    /// smaller than `min_periods` (if any), or if either variable is constant over these
+    /// observations.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of a number of pairwise-complete observations from `usize`
+    /// to `A` fails.
+    ///
+    /// [Pearson correlation coefficients]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    /// [`cov_skipnan`]: #tymethod.cov_skipnan
+    fn pearson_correlation_skipnan(
+        &self,
+        min_periods: Option<usize>,
+    ) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Return the [Ledoit-Wolf] shrinkage estimate of the covariance matrix for a
+    /// 2-dimensional array of observations `M`, with the same layout as [`cov`], together
+    /// with the shrinkage intensity `s`.
+    ///
+    /// The estimate is a convex combination of the biased sample covariance matrix `S` (i.e.
+    /// `cov(0.)`) and of a scaled identity matrix with the same trace:
+    ///
+    /// ```text
+    /// (1 - s) S + s μ I    with μ = trace(S) / r
+    /// ```
+    ///
+    /// where `s`, in `[0, 1]`, minimizes an asymptotic estimate of the expected squared
+    /// Frobenius distance to the true covariance matrix. Unlike `S`, the estimate is
+    /// well-conditioned, and is invertible even when the random variables outnumber the
+    /// observations. The shrinkage intensity is computed as in [scikit-learn], and is 0 for a
+    /// single random variable.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of `n_observations` or of the number of random variables
+    /// from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// // As many random variables as observations: `a.cov(0.)` is singular
+    /// let a = array![[1., 2., 4.],
+    ///                [2., 1., 0.],
+    ///                [5., 7., 6.]];
+    /// let (covariance, shrinkage) = a.ledoit_wolf_cov().unwrap();
+    /// assert!(shrinkage > 0. && shrinkage <= 1.);
+    /// assert!(covariance.diag().iter().all(|&variance| variance > 0.));
+    /// ```
+    ///
+    /// [Ledoit-Wolf]: https://doi.org/10.1016/S0047-259X(03)00096-4
+    /// [`cov`]: #tymethod.cov
+    /// [scikit-learn]: https://scikit-learn.org/stable/modules/generated/sklearn.covariance.ledoit_wolf.html
+    fn ledoit_wolf_cov(&self) -> Result<(Array2<A>, A), EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the [Oracle Approximating Shrinkage] (OAS) estimate of the covariance matrix for
+    /// a 2-dimensional array of observations `M`, with the same layout as [`cov`], together
+    /// with the shrinkage intensity `s`.
+    ///
+    /// The estimate has the same form as the one of [`ledoit_wolf_cov`]:
+    ///
+    /// ```text
+    /// (1 - s) S + s μ I    with μ = trace(S) / r
+    /// ```
+    ///
+    /// but `s` is chosen by iteratively approximating the oracle shrinkage intensity, assuming
+    /// normally distributed observations. With `α` the mean of the squared entries of `S` and
+    /// `o` the number of observations:
+    ///
+    /// ```text
+    ///          ⎛      α + μ²         ⎞
+    /// s = min ⎜ ――――――――――――――――― , 1 ⎟
+    ///          ⎝ (o + 1)(α - μ²/r)    ⎠
+    /// ```
+    ///
+    /// as in [scikit-learn]. The shrinkage intensity is 1 if the denominator is zero, and 0
+    /// for a single random variable.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of `n_observations` or of the number of random variables
+    /// from `usize` to `A` fails.
+    ///
+    /// [Oracle Approximating Shrinkage]: https://doi.org/10.1109/TSP.2010.2053029
+    /// [`cov`]: #tymethod.cov
+    /// [`ledoit_wolf_cov`]: #tymethod.ledoit_wolf_cov
+    /// [scikit-learn]: https://scikit-learn.org/stable/modules/generated/sklearn.covariance.oas.html
+    fn oas_cov(&self) -> Result<(Array2<A>, A), EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the [Spearman rank correlation coefficients] for a 2-dimensional array of
+    /// observations `M`, with the same layout as [`pearson_correlation`].
+    ///
+    /// Spearman's rho of two random variables is the Pearson correlation coefficient of their
+    /// ranks. Tied observations are given the average of the ranks they span, e.g. the ranks
+    /// of `[1., 5., 5., 7.]` are `[1., 2.5, 2.5, 4.]`.
+    ///
+    /// Let `R` be the matrix returned by this function. Then
+    /// ```text
+    /// R_ij = rho(rank(X_i), rank(X_j))
+    /// ```
+    ///
+    /// The coefficients involving a constant random variable are NaN.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if any element of `M` is NaN, or if the type cast of `n_observations` from
+    /// `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{arr2, aview1};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// // The other variables are monotonic functions of the first one
+    /// let a = arr2(&[[1., 2., 3., 4.],
+    ///                [1., 8., 27., 64.],
+    ///                [4., 3., 2., 1.]]);
+    /// let corr = a.spearman_correlation().unwrap();
+    /// assert_abs_diff_eq!(corr.row(0), aview1(&[1., 1., -1.]), epsilon = 1e-12);
+    /// ```
+    ///
+    /// [Spearman rank correlation coefficients]: https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    fn spearman_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the [Kendall rank correlation coefficients] (tau-b) for a 2-dimensional array
+    /// of observations `M`, with the same layout as [`pearson_correlation`].
+    ///
+    /// Kendall's tau-b of two random variables, which accounts for ties, is defined as:
+    ///
+    /// ```text
+    ///                     n_c - n_d
+    /// tau_b(X, Y) = ――――――――――――――――――――――――――
+    ///               √((n_0 - n_x)(n_0 - n_y))
+    /// ```
+    ///
+    /// where `n_c` and `n_d` are the numbers of concordant and discordant pairs of
+    /// observations, `n_0 = n(n - 1) / 2` is the number of pairs, and `n_x` and `n_y` are the
+    /// numbers of pairs tied in `X` and in `Y`.
+    ///
+    /// Each coefficient is computed with [Knight's algorithm], in `O(n log n)` time for `n`
+    /// observations. The coefficients involving a constant random variable are NaN.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if any element of `M` is NaN, or if the type cast of a number of pairs from
+    /// `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// // 5 concordant and 1 discordant pairs, without ties
+    /// let a = arr2(&[[1., 2., 3., 4.],
+    ///                [1., 3., 2., 4.]]);
+    /// let corr = a.kendall_correlation().unwrap();
+    /// assert_eq!(corr[[0, 1]], 4. / 6.);
+    /// ```
+    ///
+    /// [Kendall rank correlation coefficients]: https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    /// [Knight's algorithm]: https://doi.org/10.1080/01621459.1966.10480879
+    fn kendall_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Parallel version of [`cov`](#tymethod.cov).
+    ///
+    /// Means and entries of the covariance matrix are computed in parallel on the `rayon`
+    /// thread pool. Each entry is computed as an independent dot product, so results for
+    /// floating point elements can differ from the sequential version (which relies on
+    /// matrix multiplication) within the tolerance documented in the
+    /// [crate-level documentation](index.html#crate-features).
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `ddof` is negative or greater than or equal to the number of
+    /// observations, or if the type cast of `n_observations` from `usize` to `A` fails.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_cov(&self, ddof: A) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync;
+
     private_decl! {}
 }
 
@@ -148,36 +564,511 @@ impl<A: 'static> CorrelationExt<A> for ArrayRef2<A> {
         }
     }
 
-    fn pearson_correlation(&self) -> Result<Array2<A>, EmptyInput>
-    where
-        A: Float + FromPrimitive,
-    {
-        match self.dim() {
-            (n, m) if n > 0 && m > 0 => {
-                let observation_axis = Axis(1);
-                // The ddof value doesn't matter, as long as we use the same one
-                // for computing covariance and standard deviation
-                // We choose 0 as it is the smallest number admitted by std_axis
-                let ddof = A::zero();
-                let cov = self.cov(ddof).unwrap();
-                let std = self
-                    .std_axis(observation_axis, ddof)
-                    .insert_axis(observation_axis);
-                let std_matrix = std.dot(&std.t());
-                // element-wise division
-                Ok(cov / std_matrix)
+    fn pearson_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        match self.dim() {
+            (n, m) if n > 0 && m > 0 => {
+                let observation_axis = Axis(1);
+                // The ddof value doesn't matter, as long as we use the same one
+                // for computing covariance and standard deviation
+                // We choose 0 as it is the smallest number admitted by std_axis
+                let ddof = A::zero();
+                let cov = self.cov(ddof).unwrap();
+                let std = self
+                    .std_axis(observation_axis, ddof)
+                    .insert_axis(observation_axis);
+                let std_matrix = std.dot(&std.t());
+                // element-wise division
+                Ok(cov / std_matrix)
+            }
+            _ => Err(EmptyInput),
+        }
+    }
+
+    fn cov_with(&self, other: &ArrayRef2<A>, ddof: A) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+    {
+        let observation_axis = Axis(1);
+        check_same_n_observations(self, other)?;
+        let n_observations = A::from_usize(self.len_of(observation_axis)).unwrap();
+        let dof = if ddof >= n_observations {
+            panic!(
+                "`ddof` needs to be strictly smaller than the \
+                 number of observations provided for each \
+                 random variable!"
+            )
+        } else {
+            n_observations - ddof
+        };
+        match (
+            self.mean_axis(observation_axis),
+            other.mean_axis(observation_axis),
+        ) {
+            (Some(mean), Some(other_mean)) => {
+                let denoised = self - mean.insert_axis(observation_axis);
+                let other_denoised = other - other_mean.insert_axis(observation_axis);
+                let covariance = denoised.dot(&other_denoised.t());
+                Ok(covariance.mapv_into(|x| x / dof))
+            }
+            _ => Err(MultiInputError::EmptyInput),
+        }
+    }
+
+    fn pearson_correlation_with(&self, other: &ArrayRef2<A>) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+    {
+        check_same_n_observations(self, other)?;
+        if self.is_empty() || other.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        let observation_axis = Axis(1);
+        // The ddof value doesn't matter, as long as we use the same one
+        // for computing covariance and standard deviation
+        let ddof = A::zero();
+        let cov = self.cov_with(other, ddof)?;
+        let std = self
+            .std_axis(observation_axis, ddof)
+            .insert_axis(observation_axis);
+        let other_std = other
+            .std_axis(observation_axis, ddof)
+            .insert_axis(observation_axis);
+        let std_matrix = std.dot(&other_std.t());
+        // element-wise division
+        Ok(cov / std_matrix)
+    }
+
+    fn weighted_cov(
+        &self,
+        fweights: Option<&ArrayRef1<A>>,
+        aweights: Option<&ArrayRef1<A>>,
+        ddof: A,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+    {
+        let observation_axis = Axis(1);
+        let n_observations = self.len_of(observation_axis);
+        for weights in [fweights, aweights].iter().flatten() {
+            if weights.len() != n_observations {
+                return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                    first_shape: self.shape().to_vec(),
+                    second_shape: weights.shape().to_vec(),
+                }));
+            }
+            assert!(
+                weights.iter().all(|&w| w >= A::zero()),
+                "Weights must not be negative."
+            );
+        }
+        if let Some(fweights) = fweights {
+            assert!(
+                fweights.iter().all(|&w| w.fract() == A::zero()),
+                "Frequency weights must be whole numbers."
+            );
+        }
+        if n_observations == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        let weights = match (fweights, aweights) {
+            (Some(fweights), Some(aweights)) => fweights * aweights,
+            (Some(weights), None) | (None, Some(weights)) => weights.to_owned(),
+            (None, None) => Array1::ones(n_observations),
+        };
+        let weights_sum = weights.sum();
+        let dof = match aweights {
+            Some(aweights) => weights_sum - ddof * weights.dot(aweights) / weights_sum,
+            None => weights_sum - ddof,
+        };
+        assert!(
+            dof > A::zero(),
+            "The degrees of freedom computed from `ddof` and the weights need to be \
+             strictly positive!"
+        );
+        let mean = self.dot(&weights).mapv_into(|x| x / weights_sum);
+        let denoised = self - mean.insert_axis(observation_axis);
+        let covariance = denoised.dot(&(&denoised * &weights).t());
+        Ok(covariance.mapv_into(|x| x / dof))
+    }
+
+    fn weighted_pearson_correlation(
+        &self,
+        fweights: Option<&ArrayRef1<A>>,
+        aweights: Option<&ArrayRef1<A>>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+    {
+        // The ddof value doesn't matter, as the degrees of freedom cancel out
+        let cov = self.weighted_cov(fweights, aweights, A::zero())?;
+        if self.nrows() == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        let std = cov.diag().mapv(A::sqrt).insert_axis(Axis(1));
+        let std_matrix = std.dot(&std.t());
+        // element-wise division
+        Ok(cov / std_matrix)
+    }
+
+    fn cov_skipnan(&self, ddof: A, min_periods: Option<usize>) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        assert!(ddof >= A::zero(), "`ddof` must not be less than zero");
+        if self.ncols() == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(pairwise_skipnan(self, min_periods, |moments| {
+            if moments.n <= ddof {
+                A::nan()
+            } else {
+                moments.cov_xy / (moments.n - ddof)
+            }
+        }))
+    }
+
+    fn pearson_correlation_skipnan(
+        &self,
+        min_periods: Option<usize>,
+    ) -> Result<Array2<A>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(pairwise_skipnan(self, min_periods, |moments| {
+            moments.cov_xy / (moments.cov_xx * moments.cov_yy).sqrt()
+        }))
+    }
+
+    fn ledoit_wolf_cov(&self) -> Result<(Array2<A>, A), EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        shrunk_cov(self, |denoised, covariance, mu| {
+            let n_observations = A::from_usize(denoised.ncols()).unwrap();
+            let n_variables = A::from_usize(denoised.nrows()).unwrap();
+            let squared = denoised.mapv(|x| x * x);
+            let beta_sum = squared.dot(&squared.t()).sum();
+            let delta_sum = covariance.iter().fold(A::zero(), |acc, &c| acc + c * c);
+            let beta = (beta_sum / n_observations - delta_sum) / (n_variables * n_observations);
+            // Squared Frobenius norm of `covariance - mu * I`, divided by the number of variables
+            let delta = (delta_sum - (mu + mu) * covariance.diag().sum() + n_variables * mu * mu)
+                / n_variables;
+            let beta = beta.min(delta);
+            if beta == A::zero() {
+                A::zero()
+            } else {
+                beta / delta
+            }
+        })
+    }
+
+    fn oas_cov(&self) -> Result<(Array2<A>, A), EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        shrunk_cov(self, |denoised, covariance, mu| {
+            let n_observations = A::from_usize(denoised.ncols()).unwrap();
+            let n_variables = A::from_usize(denoised.nrows()).unwrap();
+            let alpha = covariance.iter().fold(A::zero(), |acc, &c| acc + c * c)
+                / (n_variables * n_variables);
+            let numerator = alpha + mu * mu;
+            let denominator = (n_observations + A::one()) * (alpha - mu * mu / n_variables);
+            if denominator == A::zero() {
+                A::one()
+            } else {
+                (numerator / denominator).min(A::one())
+            }
+        })
+    }
+
+    fn spearman_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let mut ranks = Array2::zeros(self.raw_dim());
+        for (row, mut ranks_row) in self.rows().into_iter().zip(ranks.rows_mut()) {
+            average_ranks(row, ranks_row.view_mut());
+        }
+        ranks.pearson_correlation()
+    }
+
+    fn kendall_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let n_variables = self.nrows();
+        let mut correlation = Array2::zeros((n_variables, n_variables));
+        for i in 0..n_variables {
+            for j in i..n_variables {
+                let tau = kendall_tau_b(self.row(i), self.row(j));
+                correlation[[i, j]] = tau;
+                correlation[[j, i]] = tau;
+            }
+        }
+        Ok(correlation)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_cov(&self, ddof: A) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync,
+    {
+        let observation_axis = Axis(1);
+        let n_observations = A::from_usize(self.len_of(observation_axis)).unwrap();
+        let dof = if ddof >= n_observations {
+            panic!(
+                "`ddof` needs to be strictly smaller than the \
+                 number of observations provided for each \
+                 random variable!"
+            )
+        } else {
+            n_observations - ddof
+        };
+        if self.len_of(observation_axis) == 0 {
+            return Err(EmptyInput);
+        }
+        let mut denoised = self.to_owned();
+        denoised
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .for_each(|mut variable| {
+                let mean = variable.sum() / n_observations;
+                variable.mapv_inplace(|x| x - mean);
+            });
+        let n_variables = self.nrows();
+        let mut covariance = Array2::zeros((n_variables, n_variables));
+        Zip::indexed(&mut covariance).par_for_each(|(i, j), c| {
+            *c = denoised.row(i).dot(&denoised.row(j)) / dof;
+        });
+        Ok(covariance)
+    }
+
+    private_impl! {}
+}
+
+/// Returns `MultiInputError::ShapeMismatch` unless `a` and `b` have the same number of
+/// observations.
+fn check_same_n_observations<A>(a: &ArrayRef2<A>, b: &ArrayRef2<A>) -> Result<(), MultiInputError> {
+    if a.ncols() == b.ncols() {
+        Ok(())
+    } else {
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: a.shape().to_vec(),
+            second_shape: b.shape().to_vec(),
+        }))
+    }
+}
+
+/// Number of pairwise-complete observations of two random variables `X` and `Y`, and sums of
+/// products of their deviations from their means over these observations.
+struct PairwiseMoments<A> {
+    n: A,
+    cov_xy: A,
+    cov_xx: A,
+    cov_yy: A,
+}
+
+/// Returns the pairwise-complete moments of `x` and `y`, or `None` if there are fewer than
+/// `min_periods` pairwise-complete observations (or none at all).
+fn pairwise_moments<A>(
+    x: ArrayView1<'_, A>,
+    y: ArrayView1<'_, A>,
+    min_periods: usize,
+) -> Option<PairwiseMoments<A>>
+where
+    A: MaybeNan + Float + FromPrimitive,
+{
+    let (x, y): (Vec<A>, Vec<A>) = x
+        .iter()
+        .zip(&y)
+        .filter(|(x, y)| !MaybeNan::is_nan(*x) && !MaybeNan::is_nan(*y))
+        .unzip();
+    if x.is_empty() || x.len() < min_periods {
+        return None;
+    }
+    let n = A::from_usize(x.len()).unwrap();
+    let mean = |values: &[A]| values.iter().fold(A::zero(), |sum, &v| sum + v) / n;
+    let (x_mean, y_mean) = (mean(&x), mean(&y));
+    let mut moments = PairwiseMoments {
+        n,
+        cov_xy: A::zero(),
+        cov_xx: A::zero(),
+        cov_yy: A::zero(),
+    };
+    for (&x, &y) in x.iter().zip(&y) {
+        let (dx, dy) = (x - x_mean, y - y_mean);
+        moments.cov_xy = moments.cov_xy + dx * dy;
+        moments.cov_xx = moments.cov_xx + dx * dx;
+        moments.cov_yy = moments.cov_yy + dy * dy;
+    }
+    Some(moments)
+}
+
+/// Returns the symmetric matrix of `entry` applied to the pairwise-complete moments of each
+/// pair of rows of `a`, with NaN entries for the pairs which don't have enough
+/// pairwise-complete observations.
+fn pairwise_skipnan<A, F>(a: &ArrayRef2<A>, min_periods: Option<usize>, entry: F) -> Array2<A>
+where
+    A: MaybeNan + Float + FromPrimitive,
+    F: Fn(PairwiseMoments<A>) -> A,
+{
+    let n_variables = a.nrows();
+    let mut matrix = Array2::zeros((n_variables, n_variables));
+    for i in 0..n_variables {
+        for j in i..n_variables {
+            let value = pairwise_moments(a.row(i), a.row(j), min_periods.unwrap_or(0))
+                .map_or_else(A::nan, &entry);
+            matrix[[i, j]] = value;
+            matrix[[j, i]] = value;
+        }
+    }
+    matrix
+}
+
+/// Returns the biased sample covariance matrix `S` of `a` shrunk towards `μ I`, with
+/// `μ = trace(S) / r`, and the shrinkage intensity, computed by `shrinkage` from the centered
+/// observations, `S` and `μ`. The shrinkage intensity is 0 for a single random variable.
+fn shrunk_cov<A, F>(a: &ArrayRef2<A>, shrinkage: F) -> Result<(Array2<A>, A), EmptyInput>
+where
+    A: 'static + Float + FromPrimitive,
+    F: FnOnce(&Array2<A>, &Array2<A>, A) -> A,
+{
+    if a.is_empty() {
+        return Err(EmptyInput);
+    }
+    let observation_axis = Axis(1);
+    let n_observations = A::from_usize(a.len_of(observation_axis)).unwrap();
+    let n_variables = A::from_usize(a.nrows()).unwrap();
+    let mean = a.mean_axis(observation_axis).unwrap();
+    let denoised = a - mean.insert_axis(observation_axis);
+    let mut covariance = denoised
+        .dot(&denoised.t())
+        .mapv_into(|x| x / n_observations);
+    if a.nrows() == 1 {
+        return Ok((covariance, A::zero()));
+    }
+    let mu = covariance.diag().sum() / n_variables;
+    let shrinkage = shrinkage(&denoised, &covariance, mu);
+    covariance.mapv_inplace(|x| (A::one() - shrinkage) * x);
+    covariance.diag_mut().mapv_inplace(|x| x + shrinkage * mu);
+    Ok((covariance, shrinkage))
+}
+
+/// Compares two elements, which must not be NaN.
+fn cmp_not_nan<A: Float>(a: &A, b: &A) -> Ordering {
+    a.partial_cmp(b)
+        .expect("Rank correlations are not defined for NaN elements.")
+}
+
+/// Writes to `ranks` the ranks of the elements of `values`, starting at 1, giving to tied
+/// elements the average of the ranks they span.
+fn average_ranks<A>(values: ArrayView1<'_, A>, mut ranks: ArrayViewMut1<'_, A>)
+where
+    A: Float + FromPrimitive,
+{
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_unstable_by(|&i, &j| cmp_not_nan(&values[i], &values[j]));
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // Average of the 1-based ranks `start + 1..=end`
+        let rank = A::from_usize(start + end + 1).unwrap() / A::from_usize(2).unwrap();
+        for &index in &order[start..end] {
+            ranks[index] = rank;
+        }
+        start = end;
+    }
+}
+
+/// Returns the number of pairs of tied elements of sorted `values`, according to `eq`.
+fn n_tied_pairs<T>(values: &[T], eq: impl Fn(&T, &T) -> bool) -> usize {
+    let mut n_pairs = 0;
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = start + 1;
+        while end < values.len() && eq(&values[start], &values[end]) {
+            end += 1;
+        }
+        n_pairs += (end - start) * (end - start - 1) / 2;
+        start = end;
+    }
+    n_pairs
+}
+
+/// Sorts `values` with a bottom-up merge sort, returning the number of swaps of adjacent
+/// elements a bubble sort would perform, i.e. the number of pairs `i < j` with
+/// `values[i] > values[j]`.
+fn sort_counting_inversions<A: Float>(values: &mut Vec<A>) -> usize {
+    let n = values.len();
+    let mut buffer = values.clone();
+    let mut n_inversions = 0;
+    let mut width = 1;
+    while width < n {
+        for start in (0..n).step_by(2 * width) {
+            let middle = (start + width).min(n);
+            let end = (start + 2 * width).min(n);
+            let (mut left, mut right) = (start, middle);
+            for slot in &mut buffer[start..end] {
+                if right == end
+                    || (left < middle && cmp_not_nan(&values[left], &values[right]).is_le())
+                {
+                    *slot = values[left];
+                    left += 1;
+                } else {
+                    *slot = values[right];
+                    n_inversions += middle - left;
+                    right += 1;
+                }
             }
-            _ => Err(EmptyInput),
         }
+        std::mem::swap(values, &mut buffer);
+        width *= 2;
     }
+    n_inversions
+}
 
-    private_impl! {}
+/// Returns Kendall's tau-b of `x` and `y`, computed with Knight's algorithm.
+fn kendall_tau_b<A>(x: ArrayView1<'_, A>, y: ArrayView1<'_, A>) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let n = x.len();
+    let mut pairs: Vec<(A, A)> = x.iter().copied().zip(y.iter().copied()).collect();
+    pairs.sort_unstable_by(|a, b| cmp_not_nan(&a.0, &b.0).then_with(|| cmp_not_nan(&a.1, &b.1)));
+    let n_pairs = n * (n - 1) / 2;
+    let x_ties = n_tied_pairs(&pairs, |a, b| a.0 == b.0);
+    let joint_ties = n_tied_pairs(&pairs, |a, b| a == b);
+    let mut y_sorted: Vec<A> = pairs.into_iter().map(|(_, y)| y).collect();
+    // Pairs sorted by `x` that are out of order in `y` are the discordant ones
+    let n_discordant = sort_counting_inversions(&mut y_sorted);
+    let y_ties = n_tied_pairs(&y_sorted, |a, b| a == b);
+    // Pairs tied neither in `x` nor in `y`, which are either concordant or discordant
+    let n_untied = n_pairs + joint_ties - x_ties - y_ties;
+    let to_a = |count: usize| A::from_usize(count).unwrap();
+    let numerator = to_a(n_untied) - to_a(2 * n_discordant);
+    let denominator = (to_a(n_pairs - x_ties) * to_a(n_pairs - y_ties)).sqrt();
+    numerator / denominator
 }
 
 #[cfg(test)]
 mod cov_tests {
     use super::*;
-    use ndarray::array;
+    use ndarray::{array, s};
     use ndarray_rand::rand;
     use ndarray_rand::rand_distr::Uniform;
     use ndarray_rand::RandomExt;
@@ -270,6 +1161,51 @@ mod cov_tests {
         assert_abs_diff_eq!(a.cov(1.).unwrap(), &numpy_covariance, epsilon = 1e-8);
     }
 
+    #[test]
+    fn cross_covariance_matches_blocks_of_covariance() {
+        let a = Array::random((5, 8), Uniform::new(-1., 1.).unwrap());
+        let covariance = a.cov(1.).unwrap();
+        let (first, second) = a.view().split_at(Axis(0), 2);
+        assert_abs_diff_eq!(
+            first.cov_with(&second, 1.).unwrap(),
+            covariance.slice(s![..2, 2..]),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            second.cov_with(&first, 1.).unwrap(),
+            covariance.slice(s![2.., ..2]),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(a.cov_with(&a, 1.).unwrap(), covariance, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cross_covariance_shape_mismatch() {
+        let a = Array2::<f64>::zeros((2, 3));
+        let b = Array2::<f64>::zeros((2, 4));
+        assert_eq!(
+            a.cov_with(&b, 1.),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![2, 3],
+                second_shape: vec![2, 4],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_cross_covariance_zero_variables() {
+        let a = Array2::<f32>::zeros((0, 2));
+        let b = Array2::<f32>::zeros((3, 2));
+        assert_eq!(a.cov_with(&b, 1.).unwrap().shape(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_cross_covariance_zero_observations() {
+        let a = Array2::<f32>::zeros((2, 0));
+        // Negative ddof (-1 < 0) to avoid invalid-ddof panic
+        assert_eq!(a.cov_with(&a, -1.), Err(MultiInputError::EmptyInput));
+    }
+
     #[test]
     #[should_panic]
     // We lose precision, hence the failing assert
@@ -283,8 +1219,8 @@ mod cov_tests {
 #[cfg(test)]
 mod pearson_correlation_tests {
     use super::*;
-    use ndarray::array;
     use ndarray::Array;
+    use ndarray::{array, s};
     use ndarray_rand::rand_distr::Uniform;
     use ndarray_rand::RandomExt;
     use quickcheck_macros::quickcheck;
@@ -339,6 +1275,36 @@ mod pearson_correlation_tests {
         assert_eq!(pearson, Err(EmptyInput));
     }
 
+    #[test]
+    fn cross_correlation_matches_blocks_of_correlation() {
+        let a = Array::random((5, 8), Uniform::new(-1., 1.).unwrap());
+        let correlation = a.pearson_correlation().unwrap();
+        let (first, second) = a.view().split_at(Axis(0), 3);
+        assert_abs_diff_eq!(
+            first.pearson_correlation_with(&second).unwrap(),
+            correlation.slice(s![..3, 3..]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_cross_correlation_errors() {
+        let a = Array2::<f64>::zeros((2, 3));
+        assert!(a
+            .pearson_correlation_with(&Array2::zeros((2, 4)))
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert_eq!(
+            a.pearson_correlation_with(&Array2::zeros((0, 3))),
+            Err(MultiInputError::EmptyInput)
+        );
+        let b = Array2::<f64>::zeros((2, 0));
+        assert_eq!(
+            b.pearson_correlation_with(&b),
+            Err(MultiInputError::EmptyInput)
+        );
+    }
+
     #[test]
     fn test_for_random_array() {
         let a = array![
@@ -363,3 +1329,462 @@ mod pearson_correlation_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod weighted_cov_tests {
+    use super::*;
+    use ndarray::{array, concatenate, s};
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
+
+    #[test]
+    fn unit_weights_give_the_covariance() {
+        let a = Array::random((3, 6), Uniform::new(-1., 1.).unwrap());
+        let ones = Array1::ones(6);
+        let covariance = a.cov(1.).unwrap();
+        assert_abs_diff_eq!(
+            a.weighted_cov(None, None, 1.).unwrap(),
+            covariance,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.weighted_cov(Some(&ones), Some(&ones), 1.).unwrap(),
+            covariance,
+            epsilon = 1e-12
+        );
+        // Constant analytic weights don't matter either
+        assert_abs_diff_eq!(
+            a.weighted_cov(None, Some(&(ones * 0.3)), 1.).unwrap(),
+            covariance,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn frequency_weights_repeat_observations() {
+        let a = Array::random((3, 4), Uniform::new(-1., 1.).unwrap());
+        let fweights = array![1., 3., 0., 2.];
+        let repeated = concatenate![
+            Axis(1),
+            a.slice(s![.., 0..2]),
+            a.slice(s![.., 1..2]),
+            a.slice(s![.., 1..2]),
+            a.slice(s![.., 3..4]),
+            a.slice(s![.., 3..4])
+        ];
+        assert_abs_diff_eq!(
+            a.weighted_cov(Some(&fweights), None, 1.).unwrap(),
+            repeated.cov(1.).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.weighted_pearson_correlation(Some(&fweights), None)
+                .unwrap(),
+            repeated.pearson_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_analytic_weights() {
+        let a = array![[0., 1., 2.]];
+        let aweights = array![1., 1., 2.];
+        // Weighted mean 1.25, 2.75 / (4 - 1.5)
+        assert_abs_diff_eq!(
+            a.weighted_cov(None, Some(&aweights), 1.).unwrap(),
+            array![[1.1]],
+            epsilon = 1e-15
+        );
+        // Same as frequency weights without degrees of freedom correction
+        assert_abs_diff_eq!(
+            a.weighted_cov(None, Some(&aweights), 0.).unwrap(),
+            a.weighted_cov(Some(&aweights), None, 0.).unwrap(),
+            epsilon = 1e-15
+        );
+        // The weights are multiplied to [1., 3., 2.], with mean 7 / 6 and 6 - 8 / 6 degrees of
+        // freedom
+        assert_abs_diff_eq!(
+            a.weighted_cov(Some(&array![1., 3., 1.]), Some(&aweights), 1.)
+                .unwrap(),
+            array![[102. / 36. / (6. - 8. / 6.)]],
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = Array2::<f64>::zeros((2, 3));
+        let weights = array![1., 1.];
+        assert!(a
+            .weighted_cov(Some(&weights), None, 0.)
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(a
+            .weighted_pearson_correlation(None, Some(&weights))
+            .unwrap_err()
+            .is_shape_mismatch());
+        let b = Array2::<f64>::zeros((2, 0));
+        assert_eq!(
+            b.weighted_cov(None, None, 0.),
+            Err(MultiInputError::EmptyInput)
+        );
+        let c = Array2::<f64>::zeros((0, 3));
+        assert_eq!(c.weighted_cov(None, None, 1.).unwrap().shape(), &[0, 0]);
+        assert_eq!(
+            c.weighted_pearson_correlation(None, None),
+            Err(MultiInputError::EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Weights must not be negative.")]
+    fn test_negative_weights() {
+        let a = Array2::<f64>::zeros((2, 2));
+        let _ = a.weighted_cov(None, Some(&array![1., -1.]), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "Frequency weights must be whole numbers.")]
+    fn test_fractional_frequency_weights() {
+        let a = Array2::<f64>::zeros((2, 2));
+        let _ = a.weighted_cov(Some(&array![1., 0.5]), None, 0.);
+    }
+
+    #[test]
+    fn test_fractional_analytic_weights() {
+        let a = array![[1., 2., 4.]];
+        assert!(a
+            .weighted_cov(None, Some(&array![0.5, 1., 1.5]), 0.)
+            .is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "need to be strictly positive")]
+    fn test_invalid_ddof() {
+        let a = Array2::<f64>::zeros((2, 3));
+        let _ = a.weighted_cov(Some(&array![1., 0., 1.]), None, 2.);
+    }
+}
+
+#[cfg(test)]
+mod skipnan_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
+
+    #[test]
+    fn without_nan_matches_cov_and_pearson_correlation() {
+        let a = Array::random((4, 7), Uniform::new(-1., 1.).unwrap());
+        assert_abs_diff_eq!(
+            a.cov_skipnan(1., None).unwrap(),
+            a.cov(1.).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.pearson_correlation_skipnan(Some(7)).unwrap(),
+            a.pearson_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn uses_pairwise_complete_observations() {
+        let a = array![
+            [1., f64::NAN, 4., 2., 8.],
+            [2., 3., f64::NAN, 1., 0.],
+            [f64::NAN, 1., 5., 9., 2.]
+        ];
+        let cov = a.cov_skipnan(1., None).unwrap();
+        let corr = a.pearson_correlation_skipnan(None).unwrap();
+        for (i, j, observations) in [
+            (0, 0, vec![0, 2, 3, 4]),
+            (0, 1, vec![0, 3, 4]),
+            (0, 2, vec![2, 3, 4]),
+            (1, 2, vec![1, 3, 4]),
+        ] {
+            let complete = a.select(Axis(0), &[i, j]).select(Axis(1), &observations);
+            let complete_cov = complete.cov(1.).unwrap();
+            let complete_corr = complete.pearson_correlation().unwrap();
+            assert_abs_diff_eq!(cov[[i, j]], complete_cov[[0, 1]], epsilon = 1e-12);
+            assert_abs_diff_eq!(cov[[j, i]], complete_cov[[0, 1]], epsilon = 1e-12);
+            assert_abs_diff_eq!(corr[[i, j]], complete_corr[[0, 1]], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_min_periods_and_ddof() {
+        let a = array![
+            [1., 2., 3., f64::NAN],
+            [2., f64::NAN, f64::NAN, 5.],
+            [f64::NAN, f64::NAN, f64::NAN, f64::NAN]
+        ];
+        let cov = a.cov_skipnan(1., Some(2)).unwrap();
+        assert_eq!(cov[[0, 0]], 1.);
+        assert_eq!(cov[[1, 1]], 4.5);
+        // A single pairwise-complete observation
+        assert!(cov[[0, 1]].is_nan());
+        assert!(cov.row(2).iter().all(|x| x.is_nan()));
+        assert_eq!(a.cov_skipnan(0., None).unwrap()[[0, 1]], 0.);
+        assert!(a.cov_skipnan(0., Some(2)).unwrap()[[0, 1]].is_nan());
+        assert!(a.cov_skipnan(3., None).unwrap()[[0, 0]].is_nan());
+
+        let corr = a.pearson_correlation_skipnan(Some(3)).unwrap();
+        assert_abs_diff_eq!(corr[[0, 0]], 1., epsilon = 1e-15);
+        assert!(corr[[1, 1]].is_nan());
+        assert!(corr[[0, 1]].is_nan());
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(
+            Array2::<f64>::zeros((2, 0)).cov_skipnan(1., None),
+            Err(EmptyInput)
+        );
+        assert_eq!(
+            Array2::<f64>::zeros((0, 2))
+                .cov_skipnan(1., None)
+                .unwrap()
+                .shape(),
+            &[0, 0]
+        );
+        assert_eq!(
+            Array2::<f64>::zeros((0, 2)).pearson_correlation_skipnan(None),
+            Err(EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`ddof` must not be less than zero")]
+    fn test_negative_ddof() {
+        let _ = array![[1., 2.]].cov_skipnan(-1., None);
+    }
+}
+
+#[cfg(test)]
+mod spearman_correlation_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
+
+    #[test]
+    fn test_average_ranks() {
+        let values = array![5., 1., 5., 7., 5., -2.];
+        let mut ranks = Array1::zeros(6);
+        average_ranks(values.view(), ranks.view_mut());
+        assert_eq!(ranks, array![4., 2., 4., 6., 4., 1.]);
+    }
+
+    #[test]
+    fn is_invariant_under_increasing_transformations() {
+        let a = Array::random((3, 6), Uniform::new(-10., 10.).unwrap());
+        let b = a.mapv(f64::exp);
+        assert_abs_diff_eq!(
+            a.spearman_correlation().unwrap(),
+            b.spearman_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_with_ties() {
+        let a = array![[1., 2., 2., 3., 5.], [2., 1., 4., 3., 3.]];
+        // Ranks [1, 2.5, 2.5, 4, 5] and [2, 1, 5, 3.5, 3.5]
+        let ranks = array![[1., 2.5, 2.5, 4., 5.], [2., 1., 5., 3.5, 3.5]];
+        assert_abs_diff_eq!(
+            a.spearman_correlation().unwrap(),
+            ranks.pearson_correlation().unwrap(),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            a.spearman_correlation().unwrap()[[0, 1]],
+            7. / 19.,
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn constant_random_variables_have_nan_correlation() {
+        let a = array![[1., 1., 1.], [1., 2., 3.]];
+        let corr = a.spearman_correlation().unwrap();
+        assert!(corr[[0, 0]].is_nan());
+        assert!(corr[[0, 1]].is_nan());
+        assert_abs_diff_eq!(corr[[1, 1]], 1., epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(
+            Array2::<f64>::zeros((0, 2)).spearman_correlation(),
+            Err(EmptyInput)
+        );
+        assert_eq!(
+            Array2::<f64>::zeros((2, 0)).spearman_correlation(),
+            Err(EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Rank correlations are not defined for NaN elements.")]
+    fn test_nan_panics() {
+        let _ = array![[1., f64::NAN, 3.]].spearman_correlation();
+    }
+}
+
+#[cfg(test)]
+mod kendall_correlation_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::rand_distr::Uniform;
+    use ndarray_rand::RandomExt;
+    use quickcheck_macros::quickcheck;
+
+    /// Kendall's tau-b, looking at every pair of observations.
+    fn naive_kendall_tau_b(x: ArrayView1<'_, f64>, y: ArrayView1<'_, f64>) -> f64 {
+        let n = x.len();
+        let (mut n_concordant, mut n_discordant) = (0., 0.);
+        let (mut x_ties, mut y_ties) = (0., 0.);
+        for i in 0..n {
+            for j in i + 1..n {
+                let sign = (x[i] - x[j]) * (y[i] - y[j]);
+                if x[i] == x[j] {
+                    x_ties += 1.;
+                }
+                if y[i] == y[j] {
+                    y_ties += 1.;
+                }
+                if sign > 0. {
+                    n_concordant += 1.;
+                } else if sign < 0. {
+                    n_discordant += 1.;
+                }
+            }
+        }
+        let n_pairs = (n * (n - 1) / 2) as f64;
+        (n_concordant - n_discordant) / ((n_pairs - x_ties) * (n_pairs - y_ties)).sqrt()
+    }
+
+    #[test]
+    fn test_sort_counting_inversions() {
+        let mut values = vec![3., 1., 2., 2., 5., 0., 4.];
+        assert_eq!(sort_counting_inversions(&mut values), 9);
+        assert_eq!(values, vec![0., 1., 2., 2., 3., 4., 5.]);
+        assert_eq!(sort_counting_inversions(&mut Vec::<f64>::new()), 0);
+    }
+
+    #[quickcheck]
+    fn matches_naive_implementation(n_observations: u8) -> bool {
+        let n_observations = 2 + usize::from(n_observations) % 40;
+        // Few distinct values, to have many ties
+        let a = Array::random((3, n_observations), Uniform::new(0, 6).unwrap()).mapv(f64::from);
+        let corr = a.kendall_correlation().unwrap();
+        corr.indexed_iter().all(|((i, j), &tau)| {
+            let expected = naive_kendall_tau_b(a.row(i), a.row(j));
+            (tau.is_nan() && expected.is_nan()) || abs_diff_eq!(tau, expected, epsilon = 1e-12)
+        })
+    }
+
+    #[test]
+    fn test_with_ties() {
+        let a = array![[1., 2., 2., 3., 5.], [2., 1., 4., 3., 3.]];
+        let corr = a.kendall_correlation().unwrap();
+        // 5 concordant and 3 discordant pairs, 1 pair tied in each variable
+        assert_abs_diff_eq!(corr[[0, 1]], 2. / 9., epsilon = 1e-15);
+        assert_eq!(corr[[0, 1]], corr[[1, 0]]);
+        assert_eq!(corr[[0, 0]], 1.);
+    }
+
+    #[test]
+    fn constant_random_variables_have_nan_correlation() {
+        let a = array![[1., 1., 1.], [1., 2., 3.]];
+        let corr = a.kendall_correlation().unwrap();
+        assert!(corr[[0, 0]].is_nan());
+        assert!(corr[[0, 1]].is_nan());
+        assert_eq!(corr[[1, 1]], 1.);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(
+            Array2::<f64>::zeros((0, 2)).kendall_correlation(),
+            Err(EmptyInput)
+        );
+        assert_eq!(
+            Array2::<f64>::zeros((2, 0)).kendall_correlation(),
+            Err(EmptyInput)
+        );
+    }
+}
+
+#[cfg(test)]
+mod shrinkage_tests {
+    use super::*;
+    use ndarray::array;
+
+    fn det_3x3(m: &Array2<f64>) -> f64 {
+        m[[0, 0]] * (m[[1, 1]] * m[[2, 2]] - m[[1, 2]] * m[[2, 1]])
+            - m[[0, 1]] * (m[[1, 0]] * m[[2, 2]] - m[[1, 2]] * m[[2, 0]])
+            + m[[0, 2]] * (m[[1, 0]] * m[[2, 1]] - m[[1, 1]] * m[[2, 0]])
+    }
+
+    #[test]
+    fn test_ledoit_wolf() {
+        let a = array![[1., 2., 4., 7.], [2., 1., 0., 3.], [5., 5., 6., 4.]];
+        let (covariance, shrinkage) = a.ledoit_wolf_cov().unwrap();
+        // Computed with the formulas of scikit-learn
+        assert_abs_diff_eq!(shrinkage, 0.601_807_228_915_662_7, epsilon = 1e-12);
+        let mu = 7. / 3.;
+        let expected =
+            a.cov(0.).unwrap() * (1. - shrinkage) + Array2::<f64>::eye(3) * shrinkage * mu;
+        assert_abs_diff_eq!(covariance, expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(covariance[[0, 0]], 3.494_728_915_662_650_4, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_oas() {
+        let a = array![[1., 2., 4., 7.], [2., 1., 0., 3.], [5., 5., 6., 4.]];
+        let (covariance, shrinkage) = a.oas_cov().unwrap();
+        // Computed with the formulas of scikit-learn
+        assert_abs_diff_eq!(shrinkage, 0.955_662_650_602_409_8, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            covariance[[0, 1]],
+            0.044_337_349_397_590_15,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(covariance[[2, 2]], 2.252_048_192_771_085, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn shrunk_covariance_is_invertible_with_few_observations() {
+        let a = array![[1., 2., 4.], [2., 1., 0.], [5., 7., 6.]];
+        assert_abs_diff_eq!(det_3x3(&a.cov(0.).unwrap()), 0., epsilon = 1e-12);
+        for (covariance, shrinkage) in [a.ledoit_wolf_cov().unwrap(), a.oas_cov().unwrap()] {
+            assert!(shrinkage > 0. && shrinkage <= 1.);
+            assert!(det_3x3(&covariance) > 1e-3);
+        }
+    }
+
+    #[test]
+    fn single_random_variable_is_not_shrunk() {
+        let a = array![[1., 4., 2., 8.]];
+        let covariance = a.cov(0.).unwrap();
+        assert_eq!(a.ledoit_wolf_cov().unwrap(), (covariance.clone(), 0.));
+        assert_eq!(a.oas_cov().unwrap(), (covariance, 0.));
+    }
+
+    #[test]
+    fn constant_random_variables() {
+        let a = Array2::from_elem((2, 3), 1.5);
+        assert_eq!(a.ledoit_wolf_cov().unwrap(), (Array2::zeros((2, 2)), 0.));
+        assert_eq!(a.oas_cov().unwrap(), (Array2::zeros((2, 2)), 1.));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        for a in [Array2::<f64>::zeros((0, 2)), Array2::<f64>::zeros((2, 0))] {
+            assert_eq!(a.ledoit_wolf_cov(), Err(EmptyInput));
+            assert_eq!(a.oas_cov(), Err(EmptyInput));
+        }
+    }
+}
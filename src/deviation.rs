@@ -203,6 +203,90 @@ where
     where
         A: AddAssign + Clone + Signed + ToPrimitive;
 
+    /// Parallel version of [`count_eq`](#tymethod.count_eq).
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_count_eq(&self, other: &ArrayRef<A, D>) -> Result<usize, MultiInputError>
+    where
+        A: PartialEq + Sync;
+
+    /// Parallel version of [`count_neq`](#tymethod.count_neq).
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_count_neq(&self, other: &ArrayRef<A, D>) -> Result<usize, MultiInputError>
+    where
+        A: PartialEq + Sync;
+
+    /// Parallel version of [`sq_l2_dist`](#tymethod.sq_l2_dist).
+    ///
+    /// Partial sums are added in a different order than in the sequential version, hence
+    /// results for floating point elements can differ within the tolerance documented
+    /// in the [crate-level documentation](index.html#crate-features).
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_sq_l2_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + Send + Sync;
+
+    /// Parallel version of [`l2_dist`](#tymethod.l2_dist), see
+    /// [`par_sq_l2_dist`](#tymethod.par_sq_l2_dist) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_l2_dist(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync;
+
+    /// Parallel version of [`l1_dist`](#tymethod.l1_dist).
+    ///
+    /// Partial sums are added in a different order than in the sequential version, hence
+    /// results for floating point elements can differ within the tolerance documented
+    /// in the [crate-level documentation](index.html#crate-features).
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_l1_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + Send + Sync;
+
+    /// Parallel version of [`linf_dist`](#tymethod.linf_dist).
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_linf_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: Clone + PartialOrd + Signed + Send + Sync;
+
+    /// Parallel version of [`mean_abs_err`](#tymethod.mean_abs_err), see
+    /// [`par_l1_dist`](#tymethod.par_l1_dist) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_mean_abs_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync;
+
+    /// Parallel version of [`mean_sq_err`](#tymethod.mean_sq_err), see
+    /// [`par_sq_l2_dist`](#tymethod.par_sq_l2_dist) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_mean_sq_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync;
+
+    /// Parallel version of [`root_mean_sq_err`](#tymethod.root_mean_sq_err), see
+    /// [`par_sq_l2_dist`](#tymethod.par_sq_l2_dist) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_root_mean_sq_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync;
+
     private_decl! {}
 }
 
@@ -351,5 +435,148 @@ where
         Ok(psnr)
     }
 
+    #[cfg(feature = "rayon")]
+    fn par_count_eq(&self, other: &ArrayRef<A, D>) -> Result<usize, MultiInputError>
+    where
+        A: PartialEq + Sync,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let count = Zip::from(self).and(other).par_fold(
+            || 0,
+            |count, a, b| if a == b { count + 1 } else { count },
+            |a, b| a + b,
+        );
+
+        Ok(count)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_count_neq(&self, other: &ArrayRef<A, D>) -> Result<usize, MultiInputError>
+    where
+        A: PartialEq + Sync,
+    {
+        self.par_count_eq(other).map(|n_eq| self.len() - n_eq)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_sq_l2_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + Send + Sync,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let result = Zip::from(self).and(other).par_fold(
+            A::zero,
+            |mut result, self_i, other_i| {
+                let (a, b) = (self_i.clone(), other_i.clone());
+                let diff = a - b;
+                result += diff.clone() * diff;
+                result
+            },
+            |a, b| a + b,
+        );
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_l2_dist(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync,
+    {
+        let sq_l2_dist = self
+            .par_sq_l2_dist(other)?
+            .to_f64()
+            .expect("failed cast from type A to f64");
+
+        Ok(sq_l2_dist.sqrt())
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_l1_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + Send + Sync,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let result = Zip::from(self).and(other).par_fold(
+            A::zero,
+            |mut result, self_i, other_i| {
+                let (a, b) = (self_i.clone(), other_i.clone());
+                result += (a - b).abs();
+                result
+            },
+            |a, b| a + b,
+        );
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_linf_dist(&self, other: &ArrayRef<A, D>) -> Result<A, MultiInputError>
+    where
+        A: Clone + PartialOrd + Signed + Send + Sync,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let max = Zip::from(self).and(other).par_fold(
+            A::zero,
+            |max, self_i, other_i| {
+                let (a, b) = (self_i.clone(), other_i.clone());
+                let diff = (a - b).abs();
+                if diff > max {
+                    diff
+                } else {
+                    max
+                }
+            },
+            |a, b| if b > a { b } else { a },
+        );
+
+        Ok(max)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_mean_abs_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync,
+    {
+        let l1_dist = self
+            .par_l1_dist(other)?
+            .to_f64()
+            .expect("failed cast from type A to f64");
+        let n = self.len() as f64;
+
+        Ok(l1_dist / n)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_mean_sq_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync,
+    {
+        let sq_l2_dist = self
+            .par_sq_l2_dist(other)?
+            .to_f64()
+            .expect("failed cast from type A to f64");
+        let n = self.len() as f64;
+
+        Ok(sq_l2_dist / n)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_root_mean_sq_err(&self, other: &ArrayRef<A, D>) -> Result<f64, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive + Send + Sync,
+    {
+        let msd = self.par_mean_sq_err(other)?;
+        Ok(msd.sqrt())
+    }
+
     private_impl! {}
 }
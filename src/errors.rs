@@ -1,10 +1,13 @@
 //! Custom errors returned from our methods and functions.
 use noisy_float::types::N64;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
 
 /// An error that indicates that the input array was empty.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EmptyInput;
 
 impl fmt::Display for EmptyInput {
@@ -17,6 +20,7 @@ impl Error for EmptyInput {}
 
 /// An error computing a minimum/maximum value.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MinMaxError {
     /// The input was empty.
     EmptyInput,
@@ -47,6 +51,7 @@ impl From<EmptyInput> for MinMaxError {
 /// expect them to have exactly the same shape
 /// (e.g. `ShapeMismatch` is raised when `a.shape() == b.shape()` evaluates to `False`).
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ShapeMismatch {
     pub first_shape: Vec<usize>,
     pub second_shape: Vec<usize>,
@@ -66,6 +71,7 @@ impl Error for ShapeMismatch {}
 
 /// An error for methods that take multiple non-empty array inputs.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MultiInputError {
     /// One or more of the arrays were empty.
     EmptyInput,
@@ -116,6 +122,7 @@ impl From<ShapeMismatch> for MultiInputError {
 
 /// An error computing a quantile.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum QuantileError {
     /// The input was empty.
     EmptyInput,
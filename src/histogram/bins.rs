@@ -1,6 +1,10 @@
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
 use ndarray::prelude::*;
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::fmt;
 use std::ops::{Index, Range};
 
 /// A sorted collection of type `A` elements used to represent the boundaries of intervals, i.e.
@@ -30,10 +34,40 @@ use std::ops::{Index, Range};
 ///
 /// [`Bins`]: struct.Bins.html
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Edges<A: Ord> {
     edges: Vec<A>,
 }
 
+#[cfg(feature = "serde")]
+impl<'de, A> Deserialize<'de> for Edges<A>
+where
+    A: Ord + Deserialize<'de>,
+{
+    /// Deserializes an `Edges<A>`, failing if the edges are not sorted in strictly increasing
+    /// order (i.e. if they are not sorted or contain duplicates), as guaranteed by
+    /// `Edges::from`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Edges")]
+        struct UncheckedEdges<A> {
+            edges: Vec<A>,
+        }
+
+        let UncheckedEdges { edges } = UncheckedEdges::deserialize(deserializer)?;
+        if edges.windows(2).all(|w| w[0] < w[1]) {
+            Ok(Edges { edges })
+        } else {
+            Err(de::Error::custom(
+                "edges must be sorted in increasing order, without duplicates",
+            ))
+        }
+    }
+}
+
 impl<A: Ord> From<Vec<A>> for Edges<A> {
     /// Converts a `Vec<A>` into an `Edges<A>`, consuming the edges.
     /// The vector will be sorted in increasing order using an unstable sorting algorithm, with
@@ -236,7 +270,8 @@ impl<A: Ord> Edges<A> {
 
 /// A sorted collection of non-overlapping 1-dimensional intervals.
 ///
-/// **Note** that all intervals are left-closed and right-open.
+/// **Note** that by default all intervals are left-closed and right-open: see [`BinClosure`] for
+/// the other available policies, e.g. to close the last bin on the right as `NumPy` does.
 ///
 /// # Examples
 ///
@@ -257,19 +292,275 @@ impl<A: Ord> Edges<A> {
 ///     n64(1.)..n64(2.)
 /// );
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// With the `serde` feature, the constant time lookup enabled by [`with_uniform_lookup`] is not
+/// serialized, since it can only be computed for `A: ToPrimitive`: deserialized bins always
+/// perform a binary search over their edges, until [`into_uniform_lookup`] is called on them.
+///
+/// [`BinClosure`]: enum.BinClosure.html
+/// [`with_uniform_lookup`]: #method.with_uniform_lookup
+/// [`into_uniform_lookup`]: #method.into_uniform_lookup
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "A: Deserialize<'de>")))]
 pub struct Bins<A: Ord> {
     edges: Edges<A>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    closure: BinClosure,
+    // Not serialized: deserialized bins use a binary search until `into_uniform_lookup`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    uniform: Option<UniformLookup<A>>,
+}
+
+/// Policy deciding which bin a value lying on an edge belongs to.
+///
+/// Let `e_0 < e_1 < ... < e_n` be the edges of `n` bins.
+///
+/// # Examples
+///
+/// Matching `NumPy`'s `histogram`, whose last bin is closed on the right:
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_stats::{
+///     histogram::{BinClosure, Bins, Edges, Grid},
+///     HistogramExt,
+/// };
+///
+/// // np.histogram([1, 2, 2, 3, 4], bins=[1, 2, 3, 4])
+/// let bins = Bins::new(Edges::from(vec![1, 2, 3, 4])).with_closure(BinClosure::LastBinClosed);
+/// let histogram = array![[1], [2], [2], [3], [4]].histogram(Grid::from(vec![bins]));
+/// assert_eq!(histogram.counts(), array![1, 2, 2].into_dyn());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BinClosure {
+    /// All bins are left-closed and right-open: `[e_i, e_{i+1})`.
+    ///
+    /// `e_n` does not belong to any bin.
+    #[default]
+    RightOpen,
+    /// All bins are left-open and right-closed: `(e_i, e_{i+1}]`.
+    ///
+    /// `e_0` does not belong to any bin.
+    LeftOpen,
+    /// All bins are left-closed and right-open, `[e_i, e_{i+1})`, except for the last one which
+    /// is closed on both sides: `[e_{n-1}, e_n]`.
+    ///
+    /// Every value between `e_0` and `e_n` (bounds included) belongs to a bin. This is the policy
+    /// used by `NumPy`'s `histogram`.
+    LastBinClosed,
+}
+
+/// Parameters used to compute the index of the bin containing a value arithmetically, for
+/// evenly spaced edges.
+#[derive(Clone)]
+struct UniformLookup<A> {
+    first_edge: f64,
+    inverse_bin_width: f64,
+    to_f64: fn(&A) -> Option<f64>,
+}
+
+impl<A> fmt::Debug for UniformLookup<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniformLookup")
+            .field("first_edge", &self.first_edge)
+            .field("inverse_bin_width", &self.inverse_bin_width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> UniformLookup<A> {
+    /// Returns the index of the bin that should contain `value`, up to an error of one bin,
+    /// or `None` if the value cannot be converted to `f64`.
+    fn guess(&self, value: &A, n_bins: usize) -> Option<usize> {
+        let position = ((self.to_f64)(value)? - self.first_edge) * self.inverse_bin_width;
+        if !position.is_finite() {
+            return None;
+        }
+        // `position` is clamped to `[0, n_bins - 1]`, so the cast is lossless
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        #[allow(clippy::cast_precision_loss)]
+        Some(position.floor().max(0.).min((n_bins - 1) as f64) as usize)
+    }
+}
+
+impl<A: Ord> PartialEq for Bins<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.edges == other.edges && self.closure == other.closure
+    }
+}
+
+impl<A: Ord> Eq for Bins<A> {}
+
+impl<A: Ord + ToPrimitive> Bins<A> {
+    /// Returns a `Bins` instance where each bin corresponds to two consecutive members of the given
+    /// [`Edges`], consuming the edges, as [`new`] does.
+    ///
+    /// If the edges are evenly spaced, [`index_of`] computes the index of the bin containing a
+    /// value arithmetically, in constant time, instead of performing a binary search over the
+    /// edges: this is worth it when binning a large number of observations.
+    /// Edges are considered evenly spaced if each of them is within a quarter of the bin width
+    /// of its ideal position; the arithmetic lookup is always double-checked against the edges,
+    /// hence the result of [`index_of`] is the same as with [`new`].
+    ///
+    /// Bins built by the [`Scott`], [`Doane`] and [`Stone`] strategies use this constructor;
+    /// the other evenly spaced [`strategies`] do not require `A: ToPrimitive` and build their
+    /// bins with [`new`], which [`into_uniform_lookup`] can convert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray_stats::histogram::{Edges, Bins};
+    /// use noisy_float::types::n64;
+    ///
+    /// let edges = Edges::from(vec![n64(0.), n64(0.1), n64(0.2), n64(0.30000000000000004)]);
+    /// let bins = Bins::with_uniform_lookup(edges);
+    /// assert!(bins.is_uniform());
+    /// assert_eq!(bins.index_of(&n64(0.25)), Some(2));
+    ///
+    /// let bins = Bins::with_uniform_lookup(Edges::from(vec![0, 1, 3]));
+    /// assert!(!bins.is_uniform());
+    /// ```
+    ///
+    /// [`Edges`]: struct.Edges.html
+    /// [`new`]: #method.new
+    /// [`index_of`]: #method.index_of
+    /// [`strategies`]: strategies/index.html
+    /// [`Scott`]: strategies/struct.Scott.html
+    /// [`Doane`]: strategies/struct.Doane.html
+    /// [`Stone`]: strategies/struct.Stone.html
+    /// [`into_uniform_lookup`]: #method.into_uniform_lookup
+    #[must_use]
+    pub fn with_uniform_lookup(edges: Edges<A>) -> Self {
+        let uniform = Self::uniform_lookup(&edges);
+        Bins {
+            edges,
+            closure: BinClosure::default(),
+            uniform,
+        }
+    }
+
+    /// Enables the constant time lookup of [`with_uniform_lookup`] on `self`, keeping its
+    /// [`BinClosure`], if its edges are evenly spaced.
+    ///
+    /// This is useful for bins built with [`new`], e.g. by a [`strategy`] generic over types
+    /// that are not `ToPrimitive`, or deserialized with the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray_stats::histogram::{BinClosure, Bins, Edges};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 2, 4])).with_closure(BinClosure::LeftOpen);
+    /// assert!(!bins.is_uniform());
+    ///
+    /// let bins = bins.into_uniform_lookup();
+    /// assert!(bins.is_uniform());
+    /// assert_eq!(bins.closure(), BinClosure::LeftOpen);
+    /// assert_eq!(bins.index_of(&4), Some(1));
+    /// ```
+    ///
+    /// [`with_uniform_lookup`]: #method.with_uniform_lookup
+    /// [`BinClosure`]: enum.BinClosure.html
+    /// [`new`]: #method.new
+    /// [`strategy`]: strategies/index.html
+    #[must_use]
+    pub fn into_uniform_lookup(mut self) -> Self {
+        self.uniform = Self::uniform_lookup(&self.edges);
+        self
+    }
+
+    fn uniform_lookup(edges: &Edges<A>) -> Option<UniformLookup<A>> {
+        if edges.len() < 2 {
+            return None;
+        }
+        let to_f64: fn(&A) -> Option<f64> = A::to_f64;
+        let first_edge = to_f64(&edges[0])?;
+        let last_edge = to_f64(&edges[edges.len() - 1])?;
+        // casting the number of bins to `f64` is exact for any realistic number of bins
+        #[allow(clippy::cast_precision_loss)]
+        let bin_width = (last_edge - first_edge) / (edges.len() - 1) as f64;
+        if !(bin_width.is_finite() && bin_width > 0.) {
+            return None;
+        }
+        for (i, edge) in edges.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let expected = first_edge + i as f64 * bin_width;
+            if (to_f64(edge)? - expected).abs() > bin_width / 4. {
+                return None;
+            }
+        }
+        Some(UniformLookup {
+            first_edge,
+            inverse_bin_width: bin_width.recip(),
+            to_f64,
+        })
+    }
 }
 
 impl<A: Ord> Bins<A> {
     /// Returns a `Bins` instance where each bin corresponds to two consecutive members of the given
     /// [`Edges`], consuming the edges.
     ///
+    /// See [`with_uniform_lookup`] to look up evenly spaced bins in constant time.
+    ///
     /// [`Edges`]: struct.Edges.html
+    /// [`with_uniform_lookup`]: #method.with_uniform_lookup
     #[must_use]
     pub fn new(edges: Edges<A>) -> Self {
-        Bins { edges }
+        Bins {
+            edges,
+            closure: BinClosure::default(),
+            uniform: None,
+        }
+    }
+
+    /// Returns `self` with the given policy deciding which bin a value lying on an edge
+    /// belongs to.
+    ///
+    /// See [`BinClosure`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray_stats::histogram::{BinClosure, Edges, Bins};
+    ///
+    /// let edges = Edges::from(vec![0, 2, 4]);
+    /// let bins = Bins::new(edges).with_closure(BinClosure::LeftOpen);
+    /// assert_eq!(bins.index_of(&0), None);
+    /// assert_eq!(bins.index_of(&2), Some(0));
+    /// assert_eq!(bins.index_of(&4), Some(1));
+    /// ```
+    ///
+    /// [`BinClosure`]: enum.BinClosure.html
+    #[must_use]
+    pub fn with_closure(mut self, closure: BinClosure) -> Self {
+        self.closure = closure;
+        self
+    }
+
+    /// Returns the policy deciding which bin a value lying on an edge belongs to.
+    #[must_use]
+    pub fn closure(&self) -> BinClosure {
+        self.closure
+    }
+
+    /// Returns `true` if the index of the bin containing a value is computed arithmetically,
+    /// i.e. if `self` has been built with [`with_uniform_lookup`] from evenly spaced edges.
+    ///
+    /// [`with_uniform_lookup`]: #method.with_uniform_lookup
+    #[must_use]
+    pub fn is_uniform(&self) -> bool {
+        self.uniform.is_some()
+    }
+
+    /// Returns the [`Edges`] delimiting the bins in `self`.
+    ///
+    /// [`Edges`]: struct.Edges.html
+    #[must_use]
+    pub fn edges(&self) -> &Edges<A> {
+        &self.edges
     }
 
     /// Returns the number of bins in `self`.
@@ -324,6 +615,9 @@ impl<A: Ord> Bins<A> {
     /// Returns the index of the bin in `self` that contains the given `value`,
     /// or returns `None` if `value` does not belong to any bins in `self`.
     ///
+    /// Values lying on an edge are assigned according to the [`closure`](#method.closure)
+    /// policy of `self`.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -361,6 +655,39 @@ impl<A: Ord> Bins<A> {
     /// );
     /// ```
     pub fn index_of(&self, value: &A) -> Option<usize> {
+        let index = self.right_open_index_of(value);
+        let last_edge = match self.closure {
+            BinClosure::RightOpen => return index,
+            _ if self.is_empty() => return None,
+            _ => &self.edges[self.len()],
+        };
+        match (self.closure, index) {
+            (BinClosure::LeftOpen, Some(i)) if *value == self.edges[i] => i.checked_sub(1),
+            (_, Some(i)) => Some(i),
+            (_, None) if value == last_edge => Some(self.len() - 1),
+            (_, None) => None,
+        }
+    }
+
+    /// Returns the index of the bin in `self` that contains the given `value`, as if the bins
+    /// were left-closed and right-open.
+    fn right_open_index_of(&self, value: &A) -> Option<usize> {
+        if let Some(uniform) = &self.uniform {
+            let n_bins = self.len();
+            if *value < self.edges[0] || *value >= self.edges[n_bins] {
+                return None;
+            }
+            if let Some(guess) = uniform.guess(value, n_bins) {
+                let contains = |i: usize| self.edges[i] <= *value && *value < self.edges[i + 1];
+                if contains(guess) {
+                    return Some(guess);
+                } else if guess > 0 && contains(guess - 1) {
+                    return Some(guess - 1);
+                } else if guess + 1 < n_bins && contains(guess + 1) {
+                    return Some(guess + 1);
+                }
+            }
+        }
         self.edges.indices_of(value).map(|t| t.0)
     }
 
@@ -388,11 +715,7 @@ impl<A: Ord> Bins<A> {
     where
         A: Clone,
     {
-        let edges_indexes = self.edges.indices_of(value);
-        edges_indexes.map(|(left, right)| Range {
-            start: self.edges[left].clone(),
-            end: self.edges[right].clone(),
-        })
+        self.index_of(value).map(|i| self.index(i))
     }
 
     /// Returns a range as the bin at the given `index` position.
@@ -502,7 +825,9 @@ mod edges_tests {
 
 #[cfg(test)]
 mod bins_tests {
-    use super::{Bins, Edges};
+    use super::{BinClosure, Bins, Edges};
+    use noisy_float::types::n64;
+    use quickcheck_macros::quickcheck;
 
     #[test]
     #[should_panic]
@@ -513,4 +838,100 @@ mod bins_tests {
         // we need at least two edges to make a valid bin!
         bins.index(0);
     }
+
+    #[quickcheck]
+    #[allow(clippy::needless_pass_by_value)]
+    fn uniform_lookup_matches_binary_search(
+        start: i16,
+        width: u8,
+        n_bins: u8,
+        values: Vec<i32>,
+    ) -> bool {
+        let width = f64::from(width) / 8. + 0.01;
+        let edges: Vec<_> = (0..=n_bins)
+            .map(|i| n64(f64::from(start) + f64::from(i) * width))
+            .collect();
+        let binary_search = Bins::new(Edges::from(edges.clone()));
+        let uniform = Bins::with_uniform_lookup(Edges::from(edges.clone()));
+        (n_bins == 0 || uniform.is_uniform())
+            && values
+                .iter()
+                .map(|&v| n64(f64::from(v) / 64.))
+                .chain(edges.iter().copied())
+                .all(|v| uniform.index_of(&v) == binary_search.index_of(&v))
+    }
+
+    #[test]
+    fn uneven_edges_use_binary_search() {
+        let bins = Bins::with_uniform_lookup(Edges::from(vec![0, 1, 2, 5]));
+        assert!(!bins.is_uniform());
+        assert_eq!(bins.index_of(&3), Some(2));
+
+        // Edges within a quarter of the bin width of their ideal position are still looked up
+        // arithmetically, and the result is always double-checked
+        let bins = Bins::with_uniform_lookup(Edges::from(vec![0, 9, 21, 30, 40]));
+        assert!(bins.is_uniform());
+        for value in -5..45 {
+            assert_eq!(
+                bins.index_of(&value),
+                bins.edges().indices_of(&value).map(|t| t.0)
+            );
+        }
+    }
+
+    #[test]
+    fn closure_policies_on_edges() {
+        let edges = Edges::from(vec![1, 2, 3, 4]);
+        let right_open = Bins::new(edges.clone());
+        let left_open = Bins::new(edges.clone()).with_closure(BinClosure::LeftOpen);
+        let last_closed = Bins::new(edges).with_closure(BinClosure::LastBinClosed);
+        let lookup = |bins: &Bins<i32>| (0..=5).map(|v| bins.index_of(&v)).collect::<Vec<_>>();
+        assert_eq!(
+            lookup(&right_open),
+            vec![None, Some(0), Some(1), Some(2), None, None]
+        );
+        assert_eq!(
+            lookup(&left_open),
+            vec![None, None, Some(0), Some(1), Some(2), None]
+        );
+        assert_eq!(
+            lookup(&last_closed),
+            vec![None, Some(0), Some(1), Some(2), Some(2), None]
+        );
+        assert_eq!(left_open.range_of(&4), Some(3..4));
+    }
+
+    #[test]
+    fn closure_policies_with_uniform_lookup() {
+        let edges: Vec<_> = (0..=10).map(|i| n64(f64::from(i) * 0.5)).collect();
+        for closure in [
+            BinClosure::RightOpen,
+            BinClosure::LeftOpen,
+            BinClosure::LastBinClosed,
+        ] {
+            let binary_search = Bins::new(Edges::from(edges.clone())).with_closure(closure);
+            let uniform =
+                Bins::with_uniform_lookup(Edges::from(edges.clone())).with_closure(closure);
+            assert!(uniform.is_uniform());
+            for v in -4..24 {
+                let v = n64(f64::from(v) / 4.);
+                assert_eq!(uniform.index_of(&v), binary_search.index_of(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn closure_policies_on_empty_bins() {
+        for closure in [BinClosure::LeftOpen, BinClosure::LastBinClosed] {
+            let bins = Bins::new(Edges::from(vec![3])).with_closure(closure);
+            assert_eq!(bins.index_of(&3), None);
+        }
+    }
+
+    #[test]
+    fn closure_is_part_of_equality() {
+        let bins = Bins::new(Edges::from(vec![0, 1]));
+        assert_eq!(bins.closure(), BinClosure::RightOpen);
+        assert_ne!(bins.clone().with_closure(BinClosure::LeftOpen), bins);
+    }
 }
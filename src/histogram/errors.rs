@@ -1,9 +1,12 @@
 use crate::errors::{EmptyInput, MinMaxError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::error;
 use std::fmt;
 
 /// Error to denote that no bin has been found for a certain observation.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinNotFound;
 
 impl fmt::Display for BinNotFound {
@@ -18,8 +21,23 @@ impl error::Error for BinNotFound {
     }
 }
 
+/// Error to denote that two histograms cannot be combined because they are not defined
+/// over the same grid.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridMismatch;
+
+impl fmt::Display for GridMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The histograms are not defined over the same grid.")
+    }
+}
+
+impl error::Error for GridMismatch {}
+
 /// Error computing the set of histogram bins.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinsBuildError {
     /// The input array was empty.
     EmptyInput,
@@ -0,0 +1,225 @@
+//! Text renderings of histograms, for external tools and quick terminal inspection.
+use super::bins::BinClosure;
+use super::histograms::Histogram;
+use ndarray::Dimension;
+use std::fmt::{self, Write as _};
+use std::io;
+
+impl<A: Ord + fmt::Display> Histogram<A> {
+    /// Writes the histogram to `writer` as CSV, with one row per bin.
+    ///
+    /// For an `n`-dimensional histogram, the header is
+    ///
+    /// ```text
+    /// lower_0,upper_0,...,lower_{n-1},upper_{n-1},count
+    /// ```
+    ///
+    /// and each row contains the lower and upper edges of the bin along each axis, as retrieved
+    /// from [`Grid::projections`], followed by its count. Bins are written in row-major order,
+    /// skipping the ones with a zero count if `skip_empty` is `true`.
+    ///
+    /// Edges are written using their `Display` implementation, without quoting.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error raised by `writer`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 1, 2])),
+    ///     Bins::new(Edges::from(vec![0, 10])),
+    /// ]);
+    /// let histogram = array![[1, 2], [1, 5]].histogram(grid);
+    ///
+    /// let mut csv = Vec::new();
+    /// histogram.write_csv(&mut csv, false)?;
+    /// assert_eq!(
+    ///     String::from_utf8(csv)?,
+    ///     "lower_0,upper_0,lower_1,upper_1,count\n\
+    ///      0,1,0,10,0\n\
+    ///      1,2,0,10,2\n"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Grid::projections`]: struct.Grid.html#method.projections
+    pub fn write_csv<W: io::Write>(&self, mut writer: W, skip_empty: bool) -> io::Result<()> {
+        let projections = self.grid().projections();
+        for axis in 0..projections.len() {
+            write!(writer, "lower_{0},upper_{0},", axis)?;
+        }
+        writeln!(writer, "count")?;
+        for (index, &count) in self.counts().indexed_iter() {
+            if skip_empty && count == 0 {
+                continue;
+            }
+            for (bins, &i) in projections.iter().zip(index.slice()) {
+                let edges = bins.edges();
+                write!(writer, "{},{},", edges[i], edges[i + 1])?;
+            }
+            writeln!(writer, "{}", count)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a bar chart of a 1-dimensional histogram, with one line per bin.
+    ///
+    /// Each line shows the bin, the bar and the count of the bin. Bars are made of `#`
+    /// characters, the longest one being `width` characters long, and are proportional to the
+    /// counts (up to rounding). The brackets around each bin follow the [`BinClosure`] of the
+    /// grid.
+    ///
+    /// **Panics** if the histogram is not 1-dimensional.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 5, 10, 100]))]);
+    /// let histogram = array![[1], [2], [3], [4], [7], [7], [42]].histogram(grid);
+    ///
+    /// assert_eq!(
+    ///     histogram.ascii_chart(8),
+    ///     "[0, 5)    | ######## 4\n\
+    ///      [5, 10)   | ####     2\n\
+    ///      [10, 100) | ##       1\n"
+    /// );
+    /// ```
+    ///
+    /// [`BinClosure`]: enum.BinClosure.html
+    #[must_use]
+    pub fn ascii_chart(&self, width: usize) -> String {
+        assert_eq!(
+            self.ndim(),
+            1,
+            "This method is only available for 1-dimensional histograms."
+        );
+        let bins = &self.grid().projections()[0];
+        let edges = bins.edges();
+        let n_bins = bins.len();
+        let labels: Vec<String> = (0..n_bins)
+            .map(|i| {
+                let (open, close) = match bins.closure() {
+                    BinClosure::RightOpen => ('[', ')'),
+                    BinClosure::LeftOpen => ('(', ']'),
+                    BinClosure::LastBinClosed if i + 1 == n_bins => ('[', ']'),
+                    BinClosure::LastBinClosed => ('[', ')'),
+                };
+                format!("{}{}, {}{}", open, edges[i], edges[i + 1], close)
+            })
+            .collect();
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+        let counts = self.counts();
+        let max_count = counts.iter().copied().max().unwrap_or(0);
+        let mut chart = String::new();
+        for (label, &count) in labels.iter().zip(&counts) {
+            // Rounded to the nearest integer, without going through floats: the products are
+            // computed as `u128`, which cannot overflow, and the result is at most `width`
+            let bar_length = if max_count == 0 {
+                0
+            } else {
+                let (count, width, max_count) = (count as u128, width as u128, max_count as u128);
+                ((2 * count * width + max_count) / (2 * max_count)) as usize
+            };
+            writeln!(
+                chart,
+                "{:<label_width$} | {:<width$} {}",
+                label,
+                "#".repeat(bar_length),
+                count,
+                label_width = label_width,
+                width = width
+            )
+            .expect("Writing to a `String` must not fail.");
+        }
+        chart
+    }
+}
+
+#[cfg(test)]
+mod export_tests {
+    use crate::histogram::{BinClosure, Bins, Edges, Grid, Histogram};
+    use crate::HistogramExt;
+    use ndarray::array;
+
+    fn grid_2d() -> Grid<i32> {
+        Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1, 3])),
+            Bins::new(Edges::from(vec![0, 2, 6])),
+        ])
+    }
+
+    fn to_csv(histogram: &Histogram<i32>, skip_empty: bool) -> String {
+        let mut csv = Vec::new();
+        histogram.write_csv(&mut csv, skip_empty).unwrap();
+        String::from_utf8(csv).unwrap()
+    }
+
+    #[test]
+    fn csv_has_one_row_per_bin() {
+        let histogram = array![[0, 0], [2, 3], [2, 5]].histogram(grid_2d());
+        assert_eq!(
+            to_csv(&histogram, false),
+            "lower_0,upper_0,lower_1,upper_1,count\n\
+             0,1,0,2,1\n\
+             0,1,2,6,0\n\
+             1,3,0,2,0\n\
+             1,3,2,6,2\n"
+        );
+        assert_eq!(
+            to_csv(&histogram, true),
+            "lower_0,upper_0,lower_1,upper_1,count\n\
+             0,1,0,2,1\n\
+             1,3,2,6,2\n"
+        );
+    }
+
+    #[test]
+    fn csv_of_empty_histogram_skipping_empty_bins_is_a_header() {
+        let histogram = Histogram::new(grid_2d());
+        assert_eq!(
+            to_csv(&histogram, true),
+            "lower_0,upper_0,lower_1,upper_1,count\n"
+        );
+    }
+
+    #[test]
+    fn ascii_chart_follows_the_closure() {
+        let bins = Bins::new(Edges::from(vec![0, 1, 2])).with_closure(BinClosure::LastBinClosed);
+        let histogram = array![[0], [1], [1], [2]].histogram(Grid::from(vec![bins]));
+        assert_eq!(
+            histogram.ascii_chart(3),
+            "[0, 1) | #   1\n\
+             [1, 2] | ### 3\n"
+        );
+        let bins = Bins::new(Edges::from(vec![0, 1])).with_closure(BinClosure::LeftOpen);
+        let histogram = Histogram::new(Grid::from(vec![bins]));
+        assert_eq!(histogram.ascii_chart(2), "(0, 1] |    0\n");
+    }
+
+    #[test]
+    fn ascii_chart_does_not_overflow_for_large_counts() {
+        let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+        let mut histogram = array![[0], [1], [1]].histogram(Grid::from(vec![bins]));
+        histogram.scale(usize::MAX / 2);
+        assert_eq!(
+            histogram.ascii_chart(4),
+            format!(
+                "[0, 1) | ##   {}\n[1, 2) | #### {}\n",
+                usize::MAX / 2,
+                usize::MAX / 2 * 2
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "only available for 1-dimensional histograms")]
+    fn ascii_chart_panics_for_multidimensional_histograms() {
+        let _ = Histogram::new(grid_2d()).ascii_chart(10);
+    }
+}
@@ -1,8 +1,16 @@
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
-use super::{bins::Bins, errors::BinsBuildError, strategies::BinsBuildingStrategy};
+use super::{
+    bins::{BinClosure, Bins, Edges},
+    errors::BinsBuildError,
+    strategies::BinsBuildingStrategy,
+};
+use crate::{maybe_nan::MaybeNan, QuantileExt};
 use itertools::izip;
-use ndarray::{ArrayRef, Axis, Ix1, Ix2};
+use ndarray::{Array1, ArrayRef, Axis, Ix1, Ix2};
+use num_traits::{FromPrimitive, NumOps, ToPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::ops::Range;
 
 /// An orthogonal partition of a rectangular region in an *n*-dimensional space, e.g.
@@ -88,6 +96,7 @@ use std::ops::Range;
 /// [`GridBuilder`]: struct.GridBuilder.html
 /// [`strategy`]: strategies/index.html
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Grid<A: Ord> {
     projections: Vec<Bins<A>>,
 }
@@ -215,6 +224,80 @@ impl<A: Ord> Grid<A> {
             .map(|(v, e)| e.index_of(v))
             .collect()
     }
+
+    /// Returns an `n-dimensional` index, of bins along each axis that contains the point, if one
+    /// exists, given a point whose coordinates may be NaN.
+    ///
+    /// Returns `None` if any coordinate of the point is NaN, or if the point is outside the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if dimensionalities of the point and the grid don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Grid};
+    /// use noisy_float::types::n64;
+    ///
+    /// let edges = Edges::from(vec![n64(-1.), n64(0.), n64(1.)]);
+    /// let bins = Bins::new(edges);
+    /// let square_grid = Grid::from(vec![bins.clone(), bins.clone()]);
+    ///
+    /// assert_eq!(square_grid.index_of_skipnan(&array![0.5, -0.7]), Some(vec![1, 0]));
+    /// assert_eq!(square_grid.index_of_skipnan(&array![f64::NAN, -0.7]), None);
+    /// ```
+    #[must_use]
+    pub fn index_of_skipnan<B>(&self, point: &ArrayRef<B, Ix1>) -> Option<Vec<usize>>
+    where
+        B: MaybeNan<NotNan = A>,
+    {
+        assert_eq!(
+            point.len(),
+            self.ndim(),
+            "Dimension mismatch: the point has {:?} dimensions, the grid \
+             expected {:?} dimensions.",
+            point.len(),
+            self.ndim()
+        );
+        point
+            .iter()
+            .zip(self.projections.iter())
+            .map(|(v, e)| e.index_of(v.try_as_not_nan()?))
+            .collect()
+    }
+}
+
+impl<A: Ord + ToPrimitive> Grid<A> {
+    /// Enables the constant time lookup of [`Bins::with_uniform_lookup`] on each projection of
+    /// the grid whose edges are evenly spaced (see [`Bins::into_uniform_lookup`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{strategies::Sqrt, GridBuilder};
+    ///
+    /// let observations = array![[1, 10], [2, 20], [3, 30], [4, 40]];
+    /// let grid = GridBuilder::<Sqrt<i32>>::from_array(&observations)
+    ///     .unwrap()
+    ///     .build()
+    ///     .into_uniform_lookup();
+    /// assert!(grid.projections().iter().all(|bins| bins.is_uniform()));
+    /// ```
+    ///
+    /// [`Bins::with_uniform_lookup`]: struct.Bins.html#method.with_uniform_lookup
+    /// [`Bins::into_uniform_lookup`]: struct.Bins.html#method.into_uniform_lookup
+    #[must_use]
+    pub fn into_uniform_lookup(self) -> Self {
+        let projections = self
+            .projections
+            .into_iter()
+            .map(Bins::into_uniform_lookup)
+            .collect();
+        Grid { projections }
+    }
 }
 
 impl<A: Ord + Clone> Grid<A> {
@@ -305,7 +388,11 @@ impl<A: Ord + Clone> Grid<A> {
 /// assert_eq!(grid, expected_grid);
 /// ```
 ///
+/// To use a different strategy, an explicit range or a fixed number of bins along some axes, see
+/// [`Grid::from_specs`] instead.
+///
 /// [`Grid`]: struct.Grid.html
+/// [`Grid::from_specs`]: struct.Grid.html#method.from_specs
 /// [`histogram`]: trait.HistogramExt.html
 /// [`strategy`]: strategies/index.html
 #[allow(clippy::module_name_repetitions)]
@@ -342,6 +429,57 @@ where
         Ok(Self { bin_builders })
     }
 
+    /// Returns a `GridBuilder` for building a [`Grid`] with a given [`strategy`] and some
+    /// observations, whose coordinates may be NaN, in a 2-dimensional array with shape
+    /// `(n_observations, n_dimension)`.
+    ///
+    /// The bins along each axis are inferred from the coordinates along that axis that are not
+    /// NaN, hence the observations do not need to be converted to a not-NaN type first (e.g.
+    /// `f64` observations can be used to build a `Grid<N64>`).
+    ///
+    /// # Errors
+    ///
+    /// It returns [`BinsBuildError`] if it is not possible to build a [`Grid`] given
+    /// the observed data according to the chosen [`strategy`] (e.g. if all the coordinates
+    /// along an axis are NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{strategies::Sqrt, Bins, Edges, Grid, GridBuilder};
+    /// use noisy_float::types::{n64, N64};
+    ///
+    /// let observations = array![[0., 4.], [f64::NAN, 0.], [2., 2.], [4., f64::NAN]];
+    /// let grid = GridBuilder::<Sqrt<N64>>::from_array_skipnan(&observations)?.build();
+    ///
+    /// let bins = Bins::new(Edges::from(vec![n64(0.), n64(2.), n64(4.), n64(6.)]));
+    /// assert_eq!(grid, Grid::from(vec![bins.clone(), bins]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`strategy`]: strategies/index.html
+    /// [`BinsBuildError`]: errors/enum.BinsBuildError.html
+    pub fn from_array_skipnan<T>(array: &ArrayRef<T, Ix2>) -> Result<Self, BinsBuildError>
+    where
+        T: MaybeNan<NotNan = A>,
+        A: Clone,
+    {
+        let bin_builders = array
+            .axis_iter(Axis(1))
+            .map(|data| {
+                let not_nan: Array1<A> = data
+                    .iter()
+                    .filter_map(MaybeNan::try_as_not_nan)
+                    .cloned()
+                    .collect();
+                B::from_array(&not_nan)
+            })
+            .collect::<Result<Vec<B>, BinsBuildError>>()?;
+        Ok(Self { bin_builders })
+    }
+
     /// Returns a [`Grid`] instance, with building parameters infered in [`from_array`], according
     /// to the specified [`strategy`] and observations provided.
     ///
@@ -358,3 +496,342 @@ where
         Grid::from(projections)
     }
 }
+
+/// The specification of the bins along one dimension of a [`Grid`] built with
+/// [`Grid::from_specs`]: a [`strategy`] or a fixed number of bins, optionally restricted to a
+/// user-supplied range.
+///
+/// Unlike [`GridBuilder`], which uses the same [`strategy`] for all the dimensions, each
+/// dimension gets its own specification:
+///
+/// - [`BinsSpec::strategy`] infers the bins from the observations with a [`strategy`].
+/// - [`BinsSpec::n_bins`] splits the range of the observations into a fixed number of evenly
+///   spaced bins.
+/// - [`with_range`] sets the range explicitly, like the `range` argument of `NumPy`'s
+///   `histogram`: observations outside of it (e.g. outliers) are ignored to infer the bins,
+///   and the bins span it exactly. The edges inferred by a [`strategy`] that lie outside of the
+///   range are dropped, and the range bounds become the first and last edges.
+///
+/// Bins spanning a range, whether set explicitly or inferred from the observations by
+/// [`BinsSpec::n_bins`], include its upper bound: as in `NumPy`'s `histogram`, the last bin is
+/// closed on the right (see [`BinClosure::LastBinClosed`]).
+///
+/// Evenly spaced bins find the bin containing an observation in constant time (see
+/// [`Bins::with_uniform_lookup`]).
+///
+/// # Examples
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_stats::histogram::{strategies::Sqrt, BinsSpec, Edges, Grid};
+///
+/// let observations = array![[1, 0], [2, 10], [3, 20], [4, 30], [1000, 40]];
+/// let grid = Grid::from_specs(
+///     &observations,
+///     &[
+///         BinsSpec::strategy::<Sqrt<_>>().with_range(0, 5),
+///         BinsSpec::n_bins(4).with_range(0, 100),
+///     ],
+/// )?;
+/// // The outlier 1000 is ignored by the `Sqrt` strategy, which infers the edges [1, 2, 3, 4, 5]
+/// assert_eq!(grid.projections()[0].edges(), &Edges::from(vec![0, 1, 2, 3, 4, 5]));
+/// assert_eq!(grid.projections()[1].edges(), &Edges::from(vec![0, 25, 50, 75, 100]));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Grid::from_specs`]: struct.Grid.html#method.from_specs
+/// [`GridBuilder`]: struct.GridBuilder.html
+/// [`strategy`]: strategies/index.html
+/// [`BinsSpec::strategy`]: #method.strategy
+/// [`BinsSpec::n_bins`]: #method.n_bins
+/// [`with_range`]: #method.with_range
+/// [`BinClosure::LastBinClosed`]: enum.BinClosure.html#variant.LastBinClosed
+/// [`Bins::with_uniform_lookup`]: struct.Bins.html#method.with_uniform_lookup
+#[derive(Clone, Debug)]
+pub struct BinsSpec<A: Ord> {
+    kind: BinsSpecKind<A>,
+    range: Option<(A, A)>,
+}
+
+/// Builds bins from 1-dimensional observations with a given strategy.
+type BuildBins<A> = fn(&ArrayRef<A, Ix1>) -> Result<Bins<A>, BinsBuildError>;
+
+#[derive(Clone, Debug)]
+enum BinsSpecKind<A: Ord> {
+    Strategy(BuildBins<A>),
+    NBins(usize),
+}
+
+impl<A: Ord> BinsSpec<A> {
+    /// Returns a specification inferring the bins from the observations with strategy `B`.
+    #[must_use]
+    pub fn strategy<B>() -> Self
+    where
+        B: BinsBuildingStrategy<Elem = A>,
+    {
+        let build = |observations: &ArrayRef<A, Ix1>| {
+            B::from_array(observations).map(|strategy| strategy.build())
+        };
+        BinsSpec {
+            kind: BinsSpecKind::Strategy(build),
+            range: None,
+        }
+    }
+
+    /// Returns a specification splitting the range into `n_bins` evenly spaced bins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_bins == 0`.
+    #[must_use]
+    pub fn n_bins(n_bins: usize) -> Self {
+        assert!(n_bins > 0, "The number of bins must be positive.");
+        BinsSpec {
+            kind: BinsSpecKind::NBins(n_bins),
+            range: None,
+        }
+    }
+
+    /// Makes the bins span the range from `min` to `max`, bounds included, instead of the
+    /// range of the observations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min >= max`.
+    #[must_use]
+    pub fn with_range(mut self, min: A, max: A) -> Self {
+        assert!(
+            min < max,
+            "The lower bound of the range must be below the upper bound."
+        );
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Returns the range set with [`with_range`], if any.
+    ///
+    /// [`with_range`]: #method.with_range
+    #[must_use]
+    pub fn range(&self) -> Option<&(A, A)> {
+        self.range.as_ref()
+    }
+}
+
+impl<A> BinsSpec<A>
+where
+    A: Ord + Clone + FromPrimitive + ToPrimitive + NumOps,
+{
+    /// Returns the bins for the given 1-dimensional observations.
+    fn build(&self, observations: &ArrayRef<A, Ix1>) -> Result<Bins<A>, BinsBuildError> {
+        let in_range: Array1<A>;
+        let observations = match &self.range {
+            Some((min, max)) => {
+                in_range = observations
+                    .iter()
+                    .filter(|&value| min <= value && value <= max)
+                    .cloned()
+                    .collect();
+                &*in_range
+            }
+            None => observations,
+        };
+        let bins = match self.kind {
+            BinsSpecKind::Strategy(build) => {
+                let bins = build(observations)?;
+                match &self.range {
+                    Some((min, max)) => {
+                        let mut edges = vec![min.clone()];
+                        edges.extend(
+                            bins.edges()
+                                .iter()
+                                .filter(|&edge| min < edge && edge < max)
+                                .cloned(),
+                        );
+                        edges.push(max.clone());
+                        Bins::new(Edges::from(edges)).with_closure(BinClosure::LastBinClosed)
+                    }
+                    None => bins,
+                }
+            }
+            BinsSpecKind::NBins(n_bins) => {
+                let (min, max) = match &self.range {
+                    Some((min, max)) => (min.clone(), max.clone()),
+                    None => (observations.min()?.clone(), observations.max()?.clone()),
+                };
+                if min >= max {
+                    return Err(BinsBuildError::Strategy);
+                }
+                let range = max.clone() - min.clone();
+                let n = A::from_usize(n_bins).ok_or(BinsBuildError::Strategy)?;
+                let mut edges = Vec::with_capacity(n_bins + 1);
+                for i in 0..n_bins {
+                    let i = A::from_usize(i).ok_or(BinsBuildError::Strategy)?;
+                    edges.push(min.clone() + range.clone() * i / n.clone());
+                }
+                edges.push(max);
+                Bins::new(Edges::from(edges)).with_closure(BinClosure::LastBinClosed)
+            }
+        };
+        Ok(bins.into_uniform_lookup())
+    }
+}
+
+impl<A> Grid<A>
+where
+    A: Ord + Clone + FromPrimitive + ToPrimitive + NumOps,
+{
+    /// Returns a [`Grid`] whose bins along the `i`-th axis are built from the `i`-th column of
+    /// `observations`, a 2-dimensional array with shape `(n_observations, n_dimension)`,
+    /// according to `specs[i]`.
+    ///
+    /// See [`BinsSpec`] for examples.
+    ///
+    /// # Errors
+    ///
+    /// It returns [`BinsBuildError`] if it is not possible to build the bins along an axis,
+    /// e.g. if there are no observations within its range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of specifications is not the number of columns of `observations`.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`BinsSpec`]: struct.BinsSpec.html
+    /// [`BinsBuildError`]: errors/enum.BinsBuildError.html
+    pub fn from_specs(
+        observations: &ArrayRef<A, Ix2>,
+        specs: &[BinsSpec<A>],
+    ) -> Result<Self, BinsBuildError> {
+        assert_eq!(
+            specs.len(),
+            observations.ncols(),
+            "Dimension mismatch: {0:?} specifications were given for {1:?}-dimensional \
+             observations.",
+            specs.len(),
+            observations.ncols()
+        );
+        let projections = izip!(specs, observations.axis_iter(Axis(1)))
+            .map(|(spec, column)| spec.build(&column))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Grid::from(projections))
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::{BinsSpec, Grid};
+    use crate::histogram::{
+        strategies::{EqualFrequency, Sqrt},
+        BinClosure, Edges,
+    };
+    use ndarray::array;
+
+    #[test]
+    fn strategies_can_be_mixed() {
+        let observations = array![
+            [1, 9],
+            [2, 1],
+            [3, 8],
+            [4, 2],
+            [5, 7],
+            [6, 3],
+            [7, 6],
+            [8, 4],
+            [9, 5]
+        ];
+        let grid = Grid::from_specs(
+            &observations,
+            &[
+                BinsSpec::strategy::<Sqrt<_>>(),
+                BinsSpec::strategy::<EqualFrequency<_>>(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            grid.projections()[0].edges(),
+            &Edges::from(vec![1, 3, 5, 7, 9, 11])
+        );
+        assert_eq!(
+            grid.projections()[1].edges(),
+            &Edges::from(vec![1, 3, 5, 7, 9])
+        );
+    }
+
+    #[test]
+    fn fixed_number_of_bins_spans_the_observations() {
+        let observations = array![[0], [3], [12]];
+        let grid = Grid::from_specs(&observations, &[BinsSpec::n_bins(3)]).unwrap();
+        let bins = &grid.projections()[0];
+        assert_eq!(bins.edges(), &Edges::from(vec![0, 4, 8, 12]));
+        assert_eq!(bins.closure(), BinClosure::LastBinClosed);
+        assert_eq!(bins.index_of(&12), Some(2));
+        assert!(bins.is_uniform());
+    }
+
+    #[test]
+    fn observations_outside_of_the_range_are_ignored() {
+        let observations = array![[-100], [1], [2], [100]];
+        let spec = BinsSpec::strategy::<EqualFrequency<_>>().with_range(0, 10);
+        assert_eq!(spec.range(), Some(&(0, 10)));
+        let grid = Grid::from_specs(&observations, &[spec]).unwrap();
+        assert_eq!(
+            grid.projections()[0].edges(),
+            &Edges::from(vec![0, 1, 2, 10])
+        );
+
+        let spec = BinsSpec::strategy::<EqualFrequency<_>>().with_range(10, 20);
+        assert!(Grid::from_specs(&observations, &[spec])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn bins_span_the_range() {
+        let observations = array![[-5, -5], [1, 1], [2, 2], [3, 3], [4, 4], [100, 100]];
+        let grid = Grid::from_specs(
+            &observations,
+            &[
+                BinsSpec::strategy::<Sqrt<_>>().with_range(0, 5),
+                BinsSpec::strategy::<Sqrt<_>>().with_range(2, 3),
+            ],
+        )
+        .unwrap();
+        for ((min, max), bins) in [(0, 5), (2, 3)].iter().zip(grid.projections()) {
+            let edges = bins.edges();
+            assert_eq!((&edges[0], &edges[edges.len() - 1]), (min, max));
+            assert_eq!(bins.closure(), BinClosure::LastBinClosed);
+            assert_eq!(bins.index_of(max), Some(bins.len() - 1));
+        }
+        // Sqrt infers the edges [1, 2, 3, 4, 5] from the observations in [0, 5]
+        assert_eq!(
+            grid.projections()[0].edges(),
+            &Edges::from(vec![0, 1, 2, 3, 4, 5])
+        );
+        assert!(grid.projections()[0].is_uniform());
+        // ...and the edges [2, 3, 4] from the observations in [2, 3]
+        assert_eq!(grid.projections()[1].edges(), &Edges::from(vec![2, 3]));
+    }
+
+    #[test]
+    fn constant_observations_need_a_range() {
+        let observations = array![[1], [1]];
+        assert!(Grid::from_specs(&observations, &[BinsSpec::n_bins(2)])
+            .unwrap_err()
+            .is_strategy());
+        let spec = BinsSpec::n_bins(2).with_range(0, 2);
+        assert!(Grid::from_specs(&observations, &[spec]).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimension mismatch")]
+    fn specs_must_match_the_dimension_of_observations() {
+        let _ = Grid::from_specs(&array![[1, 2], [3, 4]], &[BinsSpec::n_bins(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The lower bound of the range must be below the upper bound.")]
+    fn empty_ranges_are_rejected() {
+        let _ = BinsSpec::<i32>::n_bins(2).with_range(1, 1);
+    }
+}
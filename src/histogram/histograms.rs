@@ -1,53 +1,1316 @@
-use super::errors::BinNotFound;
+use super::bins::{BinClosure, Bins, Edges};
+use super::errors::{BinNotFound, GridMismatch};
 use super::grid::Grid;
+use crate::errors::{EmptyInput, QuantileError, ShapeMismatch};
+use crate::maybe_nan::MaybeNan;
 use ndarray::prelude::*;
+use ndarray::{Slice, Zip};
+use noisy_float::types::N64;
+use num_traits::{Float, ToPrimitive, Zero};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::ops::{AddAssign, Mul, Range};
+
+/// Minimum number of points binned by a single task in `HistogramExt::par_histogram`.
+#[cfg(feature = "rayon")]
+const PAR_MIN_POINTS_PER_BLOCK: usize = 1 << 10;
 
 /// Histogram data structure.
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Histogram<A: Ord> {
     counts: ArrayD<usize>,
     grid: Grid<A>,
+    flow: Option<FlowCounts>,
+}
+
+/// Counters of the observations that fell outside the grid of a [`Histogram`], kept when
+/// the histogram has been created with [`Histogram::with_flow_tracking`].
+///
+/// [`Histogram`]: struct.Histogram.html
+/// [`Histogram::with_flow_tracking`]: struct.Histogram.html#method.with_flow_tracking
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlowCounts {
+    underflow: Vec<usize>,
+    overflow: Vec<usize>,
+    out_of_range: usize,
+    unbinnable: usize,
+}
+
+impl FlowCounts {
+    fn new(ndim: usize) -> Self {
+        FlowCounts {
+            underflow: vec![0; ndim],
+            overflow: vec![0; ndim],
+            out_of_range: 0,
+            unbinnable: 0,
+        }
+    }
+
+    /// Returns, for each axis, the number of observations whose coordinate along that axis
+    /// is smaller than the first edge of the grid.
+    ///
+    /// An observation outside the grid along several axes is counted once for each of them.
+    pub fn underflow(&self) -> &[usize] {
+        &self.underflow
+    }
+
+    /// Returns, for each axis, the number of observations whose coordinate along that axis
+    /// is greater than or equal to the last edge of the grid.
+    ///
+    /// An observation outside the grid along several axes is counted once for each of them.
+    pub fn overflow(&self) -> &[usize] {
+        &self.overflow
+    }
+
+    /// Returns the number of observations that underflowed or overflowed the grid along
+    /// at least one axis.
+    pub fn out_of_range(&self) -> usize {
+        self.out_of_range
+    }
+
+    /// Returns the number of observations that could not be assigned to any bin, nor
+    /// flagged as underflow or overflow (e.g. NaN values, or observations binned on an
+    /// axis without bins).
+    pub fn unbinnable(&self) -> usize {
+        self.unbinnable
+    }
+
+    /// Returns the total number of observations that have not been binned, i.e.
+    /// [`out_of_range`] + [`unbinnable`].
+    ///
+    /// [`out_of_range`]: #method.out_of_range
+    /// [`unbinnable`]: #method.unbinnable
+    pub fn total(&self) -> usize {
+        self.out_of_range + self.unbinnable
+    }
+
+    /// Records an observation that does not belong to any bin of `grid`, given its
+    /// coordinates (`None` standing for a NaN coordinate).
+    fn record<'a, A, I>(&mut self, grid: &Grid<A>, coordinates: I)
+    where
+        A: Ord + 'a,
+        I: IntoIterator<Item = Option<&'a A>>,
+    {
+        let mut is_out_of_range = false;
+        for (axis, (bins, value)) in grid.projections().iter().zip(coordinates).enumerate() {
+            let edges = bins.edges();
+            let value = match value {
+                Some(value) if !edges.is_empty() && bins.index_of(value).is_none() => value,
+                _ => continue,
+            };
+            // A value lying on the first or last edge may not belong to any bin, depending on
+            // the closure policy of the bins
+            if *value >= edges[edges.len() - 1] {
+                self.overflow[axis] += 1;
+                is_out_of_range = true;
+            } else if *value <= edges[0] {
+                self.underflow[axis] += 1;
+                is_out_of_range = true;
+            }
+        }
+        if is_out_of_range {
+            self.out_of_range += 1;
+        } else {
+            self.unbinnable += 1;
+        }
+    }
+
+    fn merge(&mut self, other: &FlowCounts) {
+        for (a, b) in self.underflow.iter_mut().zip(&other.underflow) {
+            *a += b;
+        }
+        for (a, b) in self.overflow.iter_mut().zip(&other.overflow) {
+            *a += b;
+        }
+        self.out_of_range += other.out_of_range;
+        self.unbinnable += other.unbinnable;
+    }
+
+    fn can_subtract(&self, other: &FlowCounts) -> bool {
+        self.underflow
+            .iter()
+            .zip(&other.underflow)
+            .all(|(a, b)| b <= a)
+            && self
+                .overflow
+                .iter()
+                .zip(&other.overflow)
+                .all(|(a, b)| b <= a)
+            && other.out_of_range <= self.out_of_range
+            && other.unbinnable <= self.unbinnable
+    }
+
+    fn subtract(&mut self, other: &FlowCounts) {
+        for (a, b) in self.underflow.iter_mut().zip(&other.underflow) {
+            *a -= b;
+        }
+        for (a, b) in self.overflow.iter_mut().zip(&other.overflow) {
+            *a -= b;
+        }
+        self.out_of_range -= other.out_of_range;
+        self.unbinnable -= other.unbinnable;
+    }
+
+    fn scale(&mut self, factor: usize) {
+        let scale = |count: &mut usize| {
+            *count = count
+                .checked_mul(factor)
+                .expect("Scaling the histogram counts overflowed `usize`.");
+        };
+        self.underflow.iter_mut().for_each(scale);
+        self.overflow.iter_mut().for_each(scale);
+        scale(&mut self.out_of_range);
+        scale(&mut self.unbinnable);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A> Deserialize<'de> for Histogram<A>
+where
+    A: Ord + Deserialize<'de>,
+{
+    /// Deserializes a `Histogram<A>`, failing if the shape of the counts does not match
+    /// the shape of the grid.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Histogram")]
+        struct UncheckedHistogram<A: Ord> {
+            counts: ArrayD<usize>,
+            grid: Grid<A>,
+            #[serde(default)]
+            flow: Option<FlowCounts>,
+        }
+
+        let UncheckedHistogram { counts, grid, flow } =
+            UncheckedHistogram::deserialize(deserializer)?;
+        if counts.shape() != grid.shape().as_slice() {
+            return Err(de::Error::custom(format!(
+                "the shape of the counts, {:?}, does not match the shape of the grid, {:?}",
+                counts.shape(),
+                grid.shape()
+            )));
+        }
+        if let Some(flow) = &flow {
+            if flow.underflow.len() != grid.ndim() || flow.overflow.len() != grid.ndim() {
+                return Err(de::Error::custom(
+                    "the number of flow counters does not match the number of dimensions of the grid",
+                ));
+            }
+        }
+        Ok(Histogram { counts, grid, flow })
+    }
+}
+
+impl<A: Ord> Histogram<A> {
+    /// Returns a new instance of Histogram given a [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new(grid: Grid<A>) -> Self {
+        let counts = ArrayD::zeros(grid.shape());
+        Histogram {
+            counts,
+            grid,
+            flow: None,
+        }
+    }
+
+    /// Returns a new instance of Histogram given a [`Grid`], keeping track of the observations
+    /// that fall outside of the grid in its [`FlowCounts`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    /// let mut histogram = Histogram::with_flow_tracking(grid);
+    ///
+    /// histogram.add_observation(&array![1, 1])?;
+    /// assert!(histogram.add_observation(&array![-1, 1]).is_err());
+    /// assert!(histogram.add_observation(&array![-1, 5]).is_err());
+    ///
+    /// let flow = histogram.flow().unwrap();
+    /// assert_eq!(flow.underflow(), &[2, 0]);
+    /// assert_eq!(flow.overflow(), &[0, 1]);
+    /// assert_eq!(flow.out_of_range(), 2);
+    /// assert_eq!(histogram.total_count(), 1);
+    /// assert_eq!(histogram.entries(), 3);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`FlowCounts`]: struct.FlowCounts.html
+    pub fn with_flow_tracking(grid: Grid<A>) -> Self {
+        let flow = Some(FlowCounts::new(grid.ndim()));
+        Histogram {
+            flow,
+            ..Histogram::new(grid)
+        }
+    }
+
+    /// Adds a single observation to the histogram.
+    ///
+    /// Returns `Err(BinNotFound)` if the observation falls outside the grid: if the
+    /// histogram tracks [`flow`](#method.flow) counts, the observation is recorded there.
+    ///
+    /// **Panics** if dimensions do not match: `self.ndim() != observation.len()`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    /// use noisy_float::types::n64;
+    ///
+    /// let edges = Edges::from(vec![n64(-1.), n64(0.), n64(1.)]);
+    /// let bins = Bins::new(edges);
+    /// let square_grid = Grid::from(vec![bins.clone(), bins.clone()]);
+    /// let mut histogram = Histogram::new(square_grid);
+    ///
+    /// let observation = array![n64(0.5), n64(0.6)];
+    ///
+    /// histogram.add_observation(&observation)?;
+    ///
+    /// let histogram_matrix = histogram.counts();
+    /// let expected = array![
+    ///     [0, 0],
+    ///     [0, 1],
+    /// ];
+    /// assert_eq!(histogram_matrix, expected.into_dyn());
+    /// # Ok::<(), Box<std::error::Error>>(())
+    /// ```
+    pub fn add_observation(&mut self, observation: &ArrayRef<A, Ix1>) -> Result<(), BinNotFound> {
+        match self.grid.index_of(observation) {
+            Some(bin_index) => {
+                self.counts[&*bin_index] += 1;
+                Ok(())
+            }
+            None => {
+                if let Some(flow) = &mut self.flow {
+                    flow.record(&self.grid, observation.iter().map(Some));
+                }
+                Err(BinNotFound)
+            }
+        }
+    }
+
+    /// Adds a single observation, whose coordinates may be NaN, to the histogram.
+    ///
+    /// This allows to fill a histogram defined over not-NaN types (e.g. [`N64`]) directly
+    /// from observations of the corresponding floating point type (e.g. `f64`), without
+    /// converting them first.
+    ///
+    /// Returns `Err(BinNotFound)` if any coordinate of the observation is NaN or if the
+    /// observation falls outside the grid: if the histogram tracks [`flow`](#method.flow)
+    /// counts, the observation is recorded there (observations with NaN coordinates are
+    /// recorded as unbinnable, unless they fall outside the grid along another axis).
+    ///
+    /// **Panics** if dimensions do not match: `self.ndim() != observation.len()`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    /// use noisy_float::types::n64;
+    ///
+    /// let bins = Bins::new(Edges::from(vec![n64(-1.), n64(0.), n64(1.)]));
+    /// let mut histogram = Histogram::with_flow_tracking(Grid::from(vec![bins]));
+    ///
+    /// histogram.add_observation_skipnan(&array![0.5])?;
+    /// assert!(histogram.add_observation_skipnan(&array![f64::NAN]).is_err());
+    ///
+    /// assert_eq!(histogram.counts(), array![0, 1].into_dyn());
+    /// assert_eq!(histogram.flow().unwrap().unbinnable(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`N64`]: https://docs.rs/noisy_float/latest/noisy_float/types/type.N64.html
+    pub fn add_observation_skipnan<B>(
+        &mut self,
+        observation: &ArrayRef<B, Ix1>,
+    ) -> Result<(), BinNotFound>
+    where
+        B: MaybeNan<NotNan = A>,
+    {
+        match self.grid.index_of_skipnan(observation) {
+            Some(bin_index) => {
+                self.counts[&*bin_index] += 1;
+                Ok(())
+            }
+            None => {
+                if let Some(flow) = &mut self.flow {
+                    flow.record(&self.grid, observation.iter().map(B::try_as_not_nan));
+                }
+                Err(BinNotFound)
+            }
+        }
+    }
+
+    /// Returns the number of dimensions of the space the histogram is covering.
+    pub fn ndim(&self) -> usize {
+        debug_assert_eq!(self.counts.ndim(), self.grid.ndim());
+        self.counts.ndim()
+    }
+
+    /// Borrows the counters of the observations that fell outside the grid, if the histogram
+    /// has been created with [`with_flow_tracking`](#method.with_flow_tracking).
+    pub fn flow(&self) -> Option<&FlowCounts> {
+        self.flow.as_ref()
+    }
+
+    /// Returns the total number of observations added to the histogram: the
+    /// [`total_count`](#method.total_count) of its bins plus, if tracked, the
+    /// observations that fell outside the grid.
+    pub fn entries(&self) -> usize {
+        self.total_count() + self.flow.as_ref().map_or(0, FlowCounts::total)
+    }
+
+    /// Borrows a view on the histogram counts matrix.
+    pub fn counts(&self) -> ArrayViewD<'_, usize> {
+        self.counts.view()
+    }
+
+    /// Borrows an immutable reference to the histogram grid.
+    pub fn grid(&self) -> &Grid<A> {
+        &self.grid
+    }
+
+    /// Returns the total number of observations that have been binned in the histogram,
+    /// i.e. the sum of its counts.
+    pub fn total_count(&self) -> usize {
+        self.counts.sum()
+    }
+
+    /// Adds the counts of `other` to the counts of `self`, as if all the observations
+    /// binned in `other` had been binned in `self` too.
+    ///
+    /// [`FlowCounts`](struct.FlowCounts.html) are merged as well if both histograms track
+    /// them; if `other` does not, `self` stops tracking them.
+    ///
+    /// Returns `Err(GridMismatch)`, leaving `self` untouched, if the two histograms are not
+    /// defined over the same grid.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+    /// let mut first = Histogram::new(grid.clone());
+    /// first.add_observation(&array![0])?;
+    /// let mut second = Histogram::new(grid);
+    /// second.add_observation(&array![1])?;
+    /// second.add_observation(&array![0])?;
+    ///
+    /// first.merge(&second)?;
+    /// assert_eq!(first.counts(), array![2, 1].into_dyn());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(&mut self, other: &Histogram<A>) -> Result<(), GridMismatch> {
+        if self.grid != other.grid {
+            return Err(GridMismatch);
+        }
+        self.counts += &other.counts;
+        match (&mut self.flow, &other.flow) {
+            (Some(flow), Some(other_flow)) => flow.merge(other_flow),
+            _ => self.flow = None,
+        }
+        Ok(())
+    }
+
+    /// Subtracts the counts of `other` from the counts of `self`, e.g. to remove a background
+    /// histogram from a signal histogram.
+    ///
+    /// [`FlowCounts`](struct.FlowCounts.html) are subtracted as well if both histograms track
+    /// them; if `other` does not, `self` stops tracking them.
+    ///
+    /// Returns `Err(GridMismatch)`, leaving `self` untouched, if the two histograms are not
+    /// defined over the same grid.
+    ///
+    /// **Panics** if any count in `other` is greater than the corresponding count in `self`
+    /// (`self` is left untouched in this case as well).
+    pub fn subtract(&mut self, other: &Histogram<A>) -> Result<(), GridMismatch> {
+        if self.grid != other.grid {
+            return Err(GridMismatch);
+        }
+        let can_subtract_flow = match (&self.flow, &other.flow) {
+            (Some(flow), Some(other_flow)) => flow.can_subtract(other_flow),
+            _ => true,
+        };
+        assert!(
+            can_subtract_flow
+                && Zip::from(&self.counts)
+                    .and(&other.counts)
+                    .all(|&count, &other_count| other_count <= count),
+            "Cannot subtract a histogram with a count greater than the corresponding count in `self`."
+        );
+        self.counts -= &other.counts;
+        match (&mut self.flow, &other.flow) {
+            (Some(flow), Some(other_flow)) => flow.subtract(other_flow),
+            _ => self.flow = None,
+        }
+        Ok(())
+    }
+
+    /// Multiplies all the counts of the histogram, including its
+    /// [`FlowCounts`](struct.FlowCounts.html), by `factor`.
+    ///
+    /// Counts are integers, hence only whole-number factors are supported: use [`scaled`] to
+    /// normalize or reweight the counts by a fractional factor.
+    ///
+    /// **Panics** if any of the scaled counts overflows `usize`.
+    ///
+    /// [`scaled`]: #method.scaled
+    pub fn scale(&mut self, factor: usize) {
+        self.counts.mapv_inplace(|count| {
+            count
+                .checked_mul(factor)
+                .expect("Scaling the histogram counts overflowed `usize`.")
+        });
+        if let Some(flow) = &mut self.flow {
+            flow.scale(factor);
+        }
+    }
+
+    /// Returns the counts of the histogram multiplied by `factor`, which can be fractional,
+    /// as floats.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+    /// let histogram = array![[0], [1], [1], [1]].histogram(grid);
+    ///
+    /// assert_eq!(histogram.scaled(0.5), array![0.5, 1.5].into_dyn());
+    /// ```
+    pub fn scaled(&self, factor: f64) -> ArrayD<f64> {
+        self.counts.mapv(|count| count as f64 * factor)
+    }
+
+    /// Returns the fraction of observations that fall in each bin, i.e. the counts divided by
+    /// [`total_count`]. The returned array sums to 1.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// [`total_count`]: #method.total_count
+    pub fn probability(&self) -> Result<ArrayD<f64>, EmptyInput> {
+        let total_count = self.total_count();
+        if total_count == 0 {
+            return Err(EmptyInput);
+        }
+        let total_count = total_count as f64;
+        Ok(self.counts.mapv(|count| count as f64 / total_count))
+    }
+
+    /// Returns the cumulative counts of the histogram along `axis`: each entry is the sum of
+    /// the counts of its bin and of all the preceding bins along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    /// let histogram = array![[0, 0], [0, 2], [1, 1], [2, 0], [2, 2]].histogram(grid);
+    ///
+    /// assert_eq!(
+    ///     histogram.cumulative_counts(Axis(0)),
+    ///     array![[1, 0, 1], [1, 1, 1], [2, 1, 2]].into_dyn()
+    /// );
+    /// assert_eq!(
+    ///     histogram.cumulative_counts(Axis(1)),
+    ///     array![[1, 1, 2], [0, 1, 1], [1, 1, 2]].into_dyn()
+    /// );
+    /// ```
+    pub fn cumulative_counts(&self, axis: Axis) -> ArrayD<usize> {
+        let mut cumulative_counts = self.counts.clone();
+        cumulative_counts.accumulate_axis_inplace(axis, |&previous, current| *current += previous);
+        cumulative_counts
+    }
+
+    /// Returns the index of the most populated bin, i.e. of the bin containing the
+    /// [mode] of the binned observations.
+    ///
+    /// If several bins have the largest count, the first one (in row-major order) is returned.
+    /// The corresponding bin can be retrieved with [`Grid::index`].
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 1, 2])),
+    ///     Bins::new(Edges::from(vec![0, 5, 10])),
+    /// ]);
+    /// let histogram = array![[0, 1], [1, 6], [1, 7], [1, 3]].histogram(grid);
+    ///
+    /// let mode = histogram.mode()?;
+    /// assert_eq!(mode, vec![1, 1]);
+    /// assert_eq!(histogram.grid().index(&mode), vec![1..2, 5..10]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [mode]: https://en.wikipedia.org/wiki/Mode_(statistics)
+    /// [`Grid::index`]: struct.Grid.html#method.index
+    pub fn mode(&self) -> Result<Vec<usize>, EmptyInput> {
+        let mut mode = None;
+        let mut max_count = 0;
+        for (index, &count) in self.counts.indexed_iter() {
+            if count > max_count {
+                max_count = count;
+                mode = Some(index);
+            }
+        }
+        mode.map(|index| index.slice().to_vec()).ok_or(EmptyInput)
+    }
+}
+
+impl<A: Ord + Clone> Histogram<A> {
+    /// Returns the marginal histogram obtained by summing the counts of `self` along `axis`:
+    /// the resulting histogram is defined over the [`Bins`] of the remaining axes.
+    ///
+    /// [`FlowCounts`] are not kept: observations falling outside the grid only along `axis`
+    /// would belong to the marginal histogram, but they have not been binned.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let bins_x = Bins::new(Edges::from(vec![0, 1, 2]));
+    /// let bins_y = Bins::new(Edges::from(vec![0, 5, 10, 20]));
+    /// let grid = Grid::from(vec![bins_x.clone(), bins_y.clone()]);
+    /// let histogram = array![[0, 0], [0, 12], [1, 7], [1, 9]].histogram(grid);
+    ///
+    /// let marginal = histogram.sum_axis(Axis(0));
+    /// assert_eq!(marginal.grid(), &Grid::from(vec![bins_y]));
+    /// assert_eq!(marginal.counts(), array![1, 2, 1].into_dyn());
+    /// ```
+    ///
+    /// [`Bins`]: struct.Bins.html
+    /// [`FlowCounts`]: struct.FlowCounts.html
+    pub fn sum_axis(&self, axis: Axis) -> Histogram<A> {
+        let counts = self.counts.sum_axis(axis);
+        let mut projections = self.grid.projections().to_vec();
+        projections.remove(axis.index());
+        Histogram {
+            counts,
+            grid: Grid::from(projections),
+            flow: None,
+        }
+    }
+
+    /// Returns the projection of the histogram on `axes`, i.e. the marginal histogram obtained
+    /// by summing out all the other axes. The `i`-th axis of the projection is `axes[i]`.
+    ///
+    /// [`FlowCounts`] are not kept, see [`sum_axis`].
+    ///
+    /// **Panics** if any of `axes` is out of bounds, or if `axes` contains duplicates.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+    /// let grid = Grid::from(vec![bins.clone(), bins.clone(), bins]);
+    /// let histogram = array![[0, 0, 1], [0, 1, 1], [1, 1, 0]].histogram(grid);
+    ///
+    /// let projection = histogram.project(&[Axis(2), Axis(0)]);
+    /// assert_eq!(projection.counts(), array![[0, 1], [2, 0]].into_dyn());
+    /// ```
+    ///
+    /// [`FlowCounts`]: struct.FlowCounts.html
+    /// [`sum_axis`]: #method.sum_axis
+    pub fn project(&self, axes: &[Axis]) -> Histogram<A> {
+        let ndim = self.ndim();
+        let mut is_kept = vec![false; ndim];
+        for axis in axes {
+            assert!(
+                axis.index() < ndim,
+                "Axis {} is out of bounds for a {}-dimensional histogram.",
+                axis.index(),
+                ndim
+            );
+            assert!(
+                !is_kept[axis.index()],
+                "`axes` must not contain duplicates."
+            );
+            is_kept[axis.index()] = true;
+        }
+        // Summing out axes from the last one preserves the indices of the remaining ones.
+        let mut counts = self.counts.clone();
+        for axis in (0..ndim).rev().filter(|&axis| !is_kept[axis]) {
+            counts = counts.sum_axis(Axis(axis));
+        }
+        // Position of each kept axis among the remaining ones, in the requested order.
+        let permutation: Vec<usize> = axes
+            .iter()
+            .map(|axis| is_kept[..axis.index()].iter().filter(|&&kept| kept).count())
+            .collect();
+        let counts = counts.permuted_axes(permutation);
+        let projections = axes
+            .iter()
+            .map(|axis| self.grid.projections()[axis.index()].clone())
+            .collect::<Vec<_>>();
+        Histogram {
+            counts: counts.as_standard_layout().into_owned(),
+            grid: Grid::from(projections),
+            flow: None,
+        }
+    }
+
+    /// Returns the sub-histogram made of the bins whose indices along the `i`-th axis lie in
+    /// `ranges[i]`, for each axis.
+    ///
+    /// [`FlowCounts`] are not kept: observations in the discarded bins would fall outside
+    /// the grid of the sub-histogram, but they are not tracked separately.
+    ///
+    /// The closure policy of each axis is kept, except for [`BinClosure::LastBinClosed`] when
+    /// the last bin is discarded: the sub-histogram then uses [`BinClosure::RightOpen`], since
+    /// none of its bins contains their right edge.
+    ///
+    /// **Panics** if `ranges.len()` is not equal to the number of dimensions of the histogram,
+    /// or if any range is decreasing or out of bounds.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 1, 2, 3]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    /// let histogram = array![[0, 0], [1, 1], [1, 2], [2, 1]].histogram(grid);
+    ///
+    /// let sliced = histogram.slice(&[1..3, 1..2]);
+    /// assert_eq!(sliced.counts(), array![[1], [1]].into_dyn());
+    /// assert_eq!(
+    ///     sliced.grid(),
+    ///     &Grid::from(vec![
+    ///         Bins::new(Edges::from(vec![1, 2, 3])),
+    ///         Bins::new(Edges::from(vec![1, 2])),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`FlowCounts`]: struct.FlowCounts.html
+    /// [`BinClosure::LastBinClosed`]: enum.BinClosure.html#variant.LastBinClosed
+    /// [`BinClosure::RightOpen`]: enum.BinClosure.html#variant.RightOpen
+    pub fn slice(&self, ranges: &[Range<usize>]) -> Histogram<A> {
+        assert_eq!(
+            ranges.len(),
+            self.ndim(),
+            "A range of bins is needed for each axis of the histogram."
+        );
+        let projections = self
+            .grid
+            .projections()
+            .iter()
+            .zip(ranges)
+            .map(|(bins, range)| {
+                assert!(
+                    range.start <= range.end && range.end <= bins.len(),
+                    "The range of bins {:?} is out of bounds for an axis with {} bins.",
+                    range,
+                    bins.len()
+                );
+                let edges = bins.edges().as_array_view();
+                let edges = if range.start == range.end {
+                    // No bins left: keep a single edge to stay within the original grid
+                    edges.slice(s![range.start..(range.start + 1).min(edges.len())])
+                } else {
+                    edges.slice(s![range.start..=range.end])
+                };
+                // The last bin is only closed if it is still the last one
+                let closure = match bins.closure() {
+                    BinClosure::LastBinClosed if range.end < bins.len() => BinClosure::RightOpen,
+                    closure => closure,
+                };
+                Bins::new(Edges::from(edges.to_vec())).with_closure(closure)
+            })
+            .collect::<Vec<_>>();
+        let counts = self
+            .counts
+            .slice_each_axis(|axis| Slice::from(ranges[axis.axis.index()].clone()))
+            .to_owned();
+        Histogram {
+            counts,
+            grid: Grid::from(projections),
+            flow: None,
+        }
+    }
+
+    /// Returns a coarser histogram, obtained by merging each group of `factor` adjacent bins
+    /// along `axis` into a single bin.
+    ///
+    /// The grid still covers the same region: [`FlowCounts`], if tracked, are kept as they are.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `factor` is 0 or if the number of bins along
+    /// `axis` is not a multiple of `factor`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2, 3, 4, 5, 6]))]);
+    /// let histogram = array![[0], [1], [1], [3], [5]].histogram(grid);
+    ///
+    /// let rebinned = histogram.rebin(Axis(0), 3);
+    /// assert_eq!(rebinned.counts(), array![3, 2].into_dyn());
+    /// assert_eq!(
+    ///     rebinned.grid(),
+    ///     &Grid::from(vec![Bins::new(Edges::from(vec![0, 3, 6]))])
+    /// );
+    /// ```
+    ///
+    /// [`FlowCounts`]: struct.FlowCounts.html
+    pub fn rebin(&self, axis: Axis, factor: usize) -> Histogram<A> {
+        assert!(
+            factor > 0,
+            "The rebinning factor must be strictly positive."
+        );
+        let n_bins = self.counts.len_of(axis);
+        assert!(
+            n_bins % factor == 0,
+            "The number of bins along the axis ({}) is not a multiple of the rebinning factor ({}).",
+            n_bins,
+            factor
+        );
+        let mut shape = self.counts.shape().to_vec();
+        shape[axis.index()] = n_bins / factor;
+        let mut counts = ArrayD::zeros(shape);
+        for (mut merged, group) in counts
+            .axis_iter_mut(axis)
+            .zip(self.counts.axis_chunks_iter(axis, factor))
+        {
+            merged.assign(&group.sum_axis(axis));
+        }
+        let mut projections = self.grid.projections().to_vec();
+        let edges = projections[axis.index()]
+            .edges()
+            .iter()
+            .step_by(factor)
+            .cloned()
+            .collect::<Vec<_>>();
+        let closure = projections[axis.index()].closure();
+        projections[axis.index()] = Bins::new(Edges::from(edges)).with_closure(closure);
+        Histogram {
+            counts,
+            grid: Grid::from(projections),
+            flow: self.flow.clone(),
+        }
+    }
+}
+
+impl<A: Ord + Clone + ToPrimitive> Histogram<A> {
+    /// Returns the estimated probability density in each bin, i.e. the [`probability`] of each
+    /// bin divided by its volume (the product of the widths of the bin along each axis).
+    ///
+    /// The integral of the density over the grid is therefore 1.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if the conversion of any bin edge to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]);
+    /// let mut histogram = Histogram::new(grid);
+    /// histogram.add_observation(&array![0])?;
+    /// histogram.add_observation(&array![2])?;
+    ///
+    /// assert_eq!(histogram.probability()?, array![0.5, 0.5].into_dyn());
+    /// // The second bin is twice as wide as the first one.
+    /// assert_eq!(histogram.density()?, array![0.5, 0.25].into_dyn());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`probability`]: #method.probability
+    pub fn density(&self) -> Result<ArrayD<f64>, EmptyInput> {
+        let mut density = self.probability()?;
+        let widths: Vec<Vec<f64>> = self
+            .grid
+            .projections()
+            .iter()
+            .map(|bins| {
+                (0..bins.len())
+                    .map(|i| {
+                        let bin = bins.index(i);
+                        edge_to_f64(&bin.end) - edge_to_f64(&bin.start)
+                    })
+                    .collect()
+            })
+            .collect();
+        for (index, density) in density.indexed_iter_mut() {
+            let volume: f64 = widths
+                .iter()
+                .zip(index.slice())
+                .map(|(widths, &i)| widths[i])
+                .product();
+            *density /= volume;
+        }
+        Ok(density)
+    }
+
+    /// Returns the value of the empirical [cumulative distribution function] of a
+    /// 1-dimensional histogram at `value`, i.e. the estimated fraction of observations
+    /// smaller than or equal to `value`.
+    ///
+    /// Observations are assumed to be uniformly distributed within each bin, hence the
+    /// cumulative distribution function is interpolated linearly between the edges of the grid.
+    /// It is 0 below the first edge and 1 above the last edge. Observations that fell
+    /// outside the grid are not taken into account.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if the histogram is not 1-dimensional, or if the conversion of `value` or
+    /// of any bin edge to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    /// use noisy_float::types::n64;
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 10, 20, 40]))]);
+    /// let histogram = array![[1], [5], [12], [25], [39]].histogram(grid);
+    ///
+    /// assert_eq!(histogram.cdf(&-1)?, 0.);
+    /// assert_eq!(histogram.cdf(&15)?, 0.5);
+    /// assert_eq!(histogram.cdf(&30)?, 0.8);
+    /// assert_eq!(histogram.cdf(&40)?, 1.);
+    /// // `quantile` is the inverse of `cdf`
+    /// assert_eq!(histogram.quantile(n64(0.8))?, 30.);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [cumulative distribution function]: https://en.wikipedia.org/wiki/Empirical_distribution_function
+    pub fn cdf(&self, value: &A) -> Result<f64, EmptyInput> {
+        let (bins, counts) = self.as_1d();
+        let total_count = self.total_count();
+        if total_count == 0 {
+            return Err(EmptyInput);
+        }
+        let edges = bins.edges();
+        if *value <= edges[0] {
+            return Ok(0.);
+        }
+        let cdf = match bins.index_of(value) {
+            Some(i) => {
+                let bin = bins.index(i);
+                let (start, end) = (edge_to_f64(&bin.start), edge_to_f64(&bin.end));
+                let fraction_of_bin = (edge_to_f64(value) - start) / (end - start);
+                let count_before = counts.slice(s![..i]).sum();
+                (count_before as f64 + fraction_of_bin * counts[i] as f64) / total_count as f64
+            }
+            None => 1.,
+        };
+        Ok(cdf)
+    }
+
+    /// Returns the `q`th quantile of a 1-dimensional histogram, estimated by inverting its
+    /// (linearly interpolated) empirical cumulative distribution function, see [`cdf`].
+    ///
+    /// This allows to approximate quantiles of data sets too large to be held in memory and
+    /// passed to [`Quantile1dExt::quantile_mut`]: the precision of the estimate is bounded
+    /// by the width of the bins.
+    ///
+    /// `q` needs to be a float between 0 and 1, bounds included: `q = 0` returns the left
+    /// edge of the first non-empty bin, `q = 1` the right edge of the last non-empty bin.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// **Panics** if the histogram is not 1-dimensional, or if the conversion of any bin edge
+    /// to `f64` fails.
+    ///
+    /// [`cdf`]: #method.cdf
+    /// [`Quantile1dExt::quantile_mut`]: ../trait.Quantile1dExt.html#tymethod.quantile_mut
+    pub fn quantile(&self, q: N64) -> Result<f64, QuantileError> {
+        self.as_1d();
+        self.quantile_axis(Axis(0), q)
+    }
+
+    /// Returns the `q`th quantile of the observations along `axis`, estimated as in [`quantile`]
+    /// from the counts of the histogram summed over all the other axes.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// Returns `Err(InvalidQuantile(q))` if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any bin edge to `f64`
+    /// fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    /// use noisy_float::types::n64;
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 10, 20])),
+    ///     Bins::new(Edges::from(vec![0, 1, 2, 3, 4])),
+    /// ]);
+    /// let histogram = array![[1, 0], [5, 1], [12, 1], [15, 3]].histogram(grid);
+    ///
+    /// assert_eq!(histogram.quantile_axis(Axis(0), n64(0.25))?, 5.);
+    /// assert_eq!(histogram.quantile_axis(Axis(1), n64(0.25))?, 1.);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`quantile`]: #method.quantile
+    pub fn quantile_axis(&self, axis: Axis, q: N64) -> Result<f64, QuantileError> {
+        if !(N64::new(0.)..=N64::new(1.)).contains(&q) {
+            return Err(QuantileError::InvalidQuantile(q));
+        }
+        let bins = &self.grid.projections()[axis.index()];
+        let counts = self.marginal_counts(axis);
+        let total_count = self.total_count();
+        if total_count == 0 {
+            return Err(QuantileError::EmptyInput);
+        }
+        let target = q.raw() * total_count as f64;
+        let mut count_before = 0;
+        for (i, &count) in counts.iter().enumerate() {
+            if count > 0 && (count_before + count) as f64 >= target {
+                let bin = bins.index(i);
+                let (start, end) = (edge_to_f64(&bin.start), edge_to_f64(&bin.end));
+                let fraction_of_bin = ((target - count_before as f64) / count as f64).max(0.);
+                return Ok(start + fraction_of_bin * (end - start));
+            }
+            count_before += count;
+        }
+        unreachable!("The last non-empty bin always reaches the total count.")
+    }
+
+    /// Returns the median of the observations along `axis`, i.e. the quantile of order `0.5`
+    /// computed by [`quantile_axis`].
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any bin edge to `f64`
+    /// fails.
+    ///
+    /// [`quantile_axis`]: #method.quantile_axis
+    pub fn median_axis(&self, axis: Axis) -> Result<f64, EmptyInput> {
+        self.quantile_axis(axis, N64::new(0.5))
+            .map_err(|_| EmptyInput)
+    }
+
+    /// Returns the mean of the observations along each axis, estimated by assuming that all
+    /// the observations falling into a bin lie at its center.
+    ///
+    /// Observations that fell outside the grid are not taken into account.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if the conversion of any bin edge to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 2, 4])),
+    ///     Bins::new(Edges::from(vec![0, 10])),
+    /// ]);
+    /// let histogram = array![[0, 1], [1, 2], [3, 3]].histogram(grid);
+    ///
+    /// // The bin centers are 1 and 3 along the first axis, 5 along the second one
+    /// assert_eq!(histogram.mean()?, array![5. / 3., 5.]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mean(&self) -> Result<Array1<f64>, EmptyInput> {
+        let total_count = self.total_count();
+        if total_count == 0 {
+            return Err(EmptyInput);
+        }
+        let mean = (0..self.ndim())
+            .map(|axis| {
+                let centers = bin_centers(&self.grid.projections()[axis]);
+                let counts = self.marginal_counts(Axis(axis)).mapv(|count| count as f64);
+                counts.dot(&centers) / total_count as f64
+            })
+            .collect();
+        Ok(mean)
+    }
+
+    /// Returns the variance of the observations along each axis, estimated by assuming that
+    /// all the observations falling into a bin lie at its center.
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom": the sum of the squared
+    /// deviations from the [`mean`] is divided by `n - ddof`, where `n` is the number of
+    /// observations binned in the histogram. Observations that fell outside the grid are not
+    /// taken into account.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if `ddof` is negative or greater than or equal to the number of observations,
+    /// or if the conversion of any bin edge to `f64` fails.
+    ///
+    /// [`mean`]: #method.mean
+    pub fn var(&self, ddof: f64) -> Result<Array1<f64>, EmptyInput> {
+        let mean = self.mean()?;
+        let dof = self.degrees_of_freedom(ddof);
+        let var = mean
+            .iter()
+            .enumerate()
+            .map(|(axis, &mean)| {
+                let centers = bin_centers(&self.grid.projections()[axis]);
+                let counts = self.marginal_counts(Axis(axis)).mapv(|count| count as f64);
+                counts.dot(&centers.mapv(|center| (center - mean).powi(2))) / dof
+            })
+            .collect();
+        Ok(var)
+    }
+
+    /// Returns the covariance matrix of the observations, whose `(i, j)` element is the
+    /// covariance of their coordinates along the `i`-th and `j`-th axes, estimated by assuming
+    /// that all the observations falling into a bin lie at its center.
+    ///
+    /// `ddof` plays the same role as in [`var`], whose results are on the diagonal of the
+    /// covariance matrix.
+    ///
+    /// Returns `Err(EmptyInput)` if the histogram is empty, i.e. if no observations have been
+    /// binned.
+    ///
+    /// **Panics** if `ddof` is negative or greater than or equal to the number of observations,
+    /// or if the conversion of any bin edge to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{HistogramExt, histogram::{Edges, Bins, Grid}};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![0, 2, 4, 6]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    /// let histogram = array![[0, 0], [2, 3], [5, 4]].histogram(grid);
+    ///
+    /// // The observations are binned as if they were [1, 1], [3, 3] and [5, 5]
+    /// assert_abs_diff_eq!(histogram.cov(1.)?, array![[4., 4.], [4., 4.]]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`var`]: #method.var
+    pub fn cov(&self, ddof: f64) -> Result<Array2<f64>, EmptyInput> {
+        let mean = self.mean()?;
+        let dof = self.degrees_of_freedom(ddof);
+        let centers: Vec<_> = self.grid.projections().iter().map(bin_centers).collect();
+        let mut cov = Array2::zeros((self.ndim(), self.ndim()));
+        let mut deviations = vec![0.; self.ndim()];
+        for (index, &count) in self.counts.indexed_iter() {
+            if count == 0 {
+                continue;
+            }
+            for (axis, deviation) in deviations.iter_mut().enumerate() {
+                *deviation = centers[axis][index[axis]] - mean[axis];
+            }
+            for ((i, j), cov) in cov.indexed_iter_mut() {
+                *cov += count as f64 * deviations[i] * deviations[j];
+            }
+        }
+        Ok(cov / dof)
+    }
+
+    /// Returns the number of observations minus `ddof`.
+    ///
+    /// **Panics** if `ddof` is negative or greater than or equal to the number of observations.
+    fn degrees_of_freedom(&self, ddof: f64) -> f64 {
+        let n_observations = self.total_count() as f64;
+        assert!(
+            (0. ..n_observations).contains(&ddof),
+            "`ddof` needs to be non-negative and strictly smaller than the number of \
+             observations binned in the histogram."
+        );
+        n_observations - ddof
+    }
+
+    /// Returns the number of observations falling into each bin along `axis`, i.e. the counts
+    /// summed over all the other axes.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn marginal_counts(&self, axis: Axis) -> Array1<usize> {
+        self.counts
+            .axis_iter(axis)
+            .map(|counts| counts.sum())
+            .collect()
+    }
+
+    /// Returns the bins and the counts of a 1-dimensional histogram.
+    ///
+    /// **Panics** if the histogram is not 1-dimensional.
+    fn as_1d(&self) -> (&Bins<A>, ArrayView1<'_, usize>) {
+        assert_eq!(
+            self.ndim(),
+            1,
+            "This method is only available for 1-dimensional histograms."
+        );
+        let counts = self.counts.view().into_dimensionality::<Ix1>().unwrap();
+        (&self.grid.projections()[0], counts)
+    }
+}
+
+/// Returns the centers of the bins, as `f64`.
+///
+/// **Panics** if the conversion of any bin edge to `f64` fails.
+fn bin_centers<A: Ord + ToPrimitive>(bins: &Bins<A>) -> Array1<f64> {
+    bins.edges()
+        .as_array_view()
+        .windows(2)
+        .into_iter()
+        .map(|edges| (edge_to_f64(&edges[0]) + edge_to_f64(&edges[1])) / 2.)
+        .collect()
+}
+
+/// Converts a bin edge (or a value lying between bin edges) to `f64`.
+///
+/// **Panics** if the conversion fails.
+fn edge_to_f64<A: ToPrimitive>(edge: &A) -> f64 {
+    edge.to_f64()
+        .expect("Converting bin edges to `f64` must not fail.")
+}
+
+/// Weighted histogram data structure.
+///
+/// Each observation is binned together with a weight of type `W` (e.g. `f64`): for every
+/// bin, the histogram keeps track of the sum of the weights and of the sum of the squared
+/// weights of the observations falling into it. The latter can be used to estimate the
+/// statistical uncertainty on the content of each bin, see [`errors`].
+///
+/// [`errors`]: #method.errors
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct WeightedHistogram<A: Ord, W> {
+    sum_of_weights: ArrayD<W>,
+    sum_of_squared_weights: ArrayD<W>,
+    grid: Grid<A>,
 }
 
-impl<A: Ord> Histogram<A> {
-    /// Returns a new instance of Histogram given a [`Grid`].
+#[cfg(feature = "serde")]
+impl<'de, A, W> Deserialize<'de> for WeightedHistogram<A, W>
+where
+    A: Ord + Deserialize<'de>,
+    W: Deserialize<'de>,
+{
+    /// Deserializes a `WeightedHistogram<A, W>`, failing if the shape of the sums does not
+    /// match the shape of the grid.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "WeightedHistogram")]
+        struct UncheckedWeightedHistogram<A: Ord, W> {
+            sum_of_weights: ArrayD<W>,
+            sum_of_squared_weights: ArrayD<W>,
+            grid: Grid<A>,
+        }
+
+        let UncheckedWeightedHistogram {
+            sum_of_weights,
+            sum_of_squared_weights,
+            grid,
+        } = UncheckedWeightedHistogram::deserialize(deserializer)?;
+        let shape = grid.shape();
+        if sum_of_weights.shape() == shape.as_slice()
+            && sum_of_squared_weights.shape() == shape.as_slice()
+        {
+            Ok(WeightedHistogram {
+                sum_of_weights,
+                sum_of_squared_weights,
+                grid,
+            })
+        } else {
+            Err(de::Error::custom(format!(
+                "the shapes of the sums, {:?} and {:?}, do not match the shape of the grid, {:?}",
+                sum_of_weights.shape(),
+                sum_of_squared_weights.shape(),
+                shape
+            )))
+        }
+    }
+}
+
+impl<A: Ord, W> WeightedHistogram<A, W> {
+    /// Returns a new instance of WeightedHistogram given a [`Grid`].
     ///
     /// [`Grid`]: struct.Grid.html
-    pub fn new(grid: Grid<A>) -> Self {
-        let counts = ArrayD::zeros(grid.shape());
-        Histogram { counts, grid }
+    pub fn new(grid: Grid<A>) -> Self
+    where
+        W: Clone + Zero,
+    {
+        let sum_of_weights = ArrayD::zeros(grid.shape());
+        let sum_of_squared_weights = ArrayD::zeros(grid.shape());
+        WeightedHistogram {
+            sum_of_weights,
+            sum_of_squared_weights,
+            grid,
+        }
     }
 
-    /// Adds a single observation to the histogram.
+    /// Adds a single observation with the given `weight` to the histogram.
     ///
     /// **Panics** if dimensions do not match: `self.ndim() != observation.len()`.
     ///
     /// # Example:
     /// ```
     /// use ndarray::array;
-    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
-    /// use noisy_float::types::n64;
-    ///
-    /// let edges = Edges::from(vec![n64(-1.), n64(0.), n64(1.)]);
-    /// let bins = Bins::new(edges);
-    /// let square_grid = Grid::from(vec![bins.clone(), bins.clone()]);
-    /// let mut histogram = Histogram::new(square_grid);
+    /// use ndarray_stats::histogram::{Edges, Bins, Grid, WeightedHistogram};
     ///
-    /// let observation = array![n64(0.5), n64(0.6)];
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+    /// let mut histogram = WeightedHistogram::new(grid);
     ///
-    /// histogram.add_observation(&observation)?;
+    /// histogram.add_observation(&array![1], 0.5)?;
+    /// histogram.add_observation(&array![1], 2.)?;
     ///
-    /// let histogram_matrix = histogram.counts();
-    /// let expected = array![
-    ///     [0, 0],
-    ///     [0, 1],
-    /// ];
-    /// assert_eq!(histogram_matrix, expected.into_dyn());
-    /// # Ok::<(), Box<std::error::Error>>(())
+    /// assert_eq!(histogram.sum_of_weights(), array![0., 2.5].into_dyn());
+    /// assert_eq!(histogram.sum_of_squared_weights(), array![0., 4.25].into_dyn());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn add_observation(&mut self, observation: &ArrayRef<A, Ix1>) -> Result<(), BinNotFound> {
+    pub fn add_observation(
+        &mut self,
+        observation: &ArrayRef<A, Ix1>,
+        weight: W,
+    ) -> Result<(), BinNotFound>
+    where
+        W: Clone + AddAssign + Mul<Output = W>,
+    {
         match self.grid.index_of(observation) {
             Some(bin_index) => {
-                self.counts[&*bin_index] += 1;
+                self.sum_of_squared_weights[&*bin_index] += weight.clone() * weight.clone();
+                self.sum_of_weights[&*bin_index] += weight;
                 Ok(())
             }
             None => Err(BinNotFound),
@@ -56,13 +1319,29 @@ impl<A: Ord> Histogram<A> {
 
     /// Returns the number of dimensions of the space the histogram is covering.
     pub fn ndim(&self) -> usize {
-        debug_assert_eq!(self.counts.ndim(), self.grid.ndim());
-        self.counts.ndim()
+        debug_assert_eq!(self.sum_of_weights.ndim(), self.grid.ndim());
+        self.sum_of_weights.ndim()
     }
 
-    /// Borrows a view on the histogram counts matrix.
-    pub fn counts(&self) -> ArrayViewD<'_, usize> {
-        self.counts.view()
+    /// Borrows a view on the sum of the weights of the observations in each bin.
+    pub fn sum_of_weights(&self) -> ArrayViewD<'_, W> {
+        self.sum_of_weights.view()
+    }
+
+    /// Borrows a view on the sum of the squared weights of the observations in each bin.
+    pub fn sum_of_squared_weights(&self) -> ArrayViewD<'_, W> {
+        self.sum_of_squared_weights.view()
+    }
+
+    /// Returns the statistical error on the content of each bin, estimated as the square root
+    /// of the sum of the squared weights of the observations in the bin.
+    ///
+    /// For unit weights, this is the usual Poisson error `√N`.
+    pub fn errors(&self) -> ArrayD<W>
+    where
+        W: Float,
+    {
+        self.sum_of_squared_weights.mapv(W::sqrt)
     }
 
     /// Borrows an immutable reference to the histogram grid.
@@ -131,6 +1410,80 @@ pub trait HistogramExt<A> {
     where
         A: Ord;
 
+    /// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram) for a 2-dimensional
+    /// array of points `M`, keeping track of the points that fall outside the grid in its
+    /// [`FlowCounts`](struct.FlowCounts.html).
+    ///
+    /// See [`histogram`](#tymethod.histogram) for more details on the layout of `M`, and
+    /// [`Histogram::with_flow_tracking`](struct.Histogram.html#method.with_flow_tracking)
+    /// for more details on flow counts.
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    fn histogram_with_flow(&self, grid: Grid<A>) -> Histogram<A>
+    where
+        A: Ord;
+
+    /// Returns the weighted [histogram](https://en.wikipedia.org/wiki/Histogram)
+    /// for a 2-dimensional array of points `M`, where the `i`-th point (i.e. the `i`-th row
+    /// of `M`) has weight `weights[i]`.
+    ///
+    /// See [`histogram`](#tymethod.histogram) for more details on the layout of `M`.
+    ///
+    /// Important: points outside the grid are ignored!
+    ///
+    /// Returns `Err(ShapeMismatch)` if the length of `weights` is different from the
+    /// number of points.
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{
+    ///     HistogramExt,
+    ///     histogram::{Bins, Edges, Grid},
+    /// };
+    ///
+    /// let observations = array![[0, 1], [1, 0], [1, 1], [5, 5]];
+    /// let weights = array![0.5, 1.5, 2., 10.];
+    /// let bins = Bins::new(Edges::from(vec![0, 1, 2]));
+    /// let grid = Grid::from(vec![bins.clone(), bins]);
+    ///
+    /// let histogram = observations.weighted_histogram(grid, &weights)?;
+    /// assert_eq!(
+    ///     histogram.sum_of_weights(),
+    ///     array![[0., 0.5], [1.5, 2.]].into_dyn()
+    /// );
+    /// assert_eq!(
+    ///     histogram.sum_of_squared_weights(),
+    ///     array![[0., 0.25], [2.25, 4.]].into_dyn()
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn weighted_histogram<W>(
+        &self,
+        grid: Grid<A>,
+        weights: &ArrayRef<W, Ix1>,
+    ) -> Result<WeightedHistogram<A, W>, ShapeMismatch>
+    where
+        A: Ord,
+        W: Clone + Zero + AddAssign + Mul<Output = W>;
+
+    /// Parallel version of [`histogram`](#tymethod.histogram).
+    ///
+    /// Points are split into blocks that are binned on the `rayon` thread pool, and the
+    /// counts of all blocks are then added up: the result is identical to the one returned
+    /// by [`histogram`](#tymethod.histogram).
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_histogram(&self, grid: Grid<A>) -> Histogram<A>
+    where
+        A: Ord + Send + Sync;
+
     private_decl! {}
 }
 
@@ -146,5 +1499,680 @@ where
         histogram
     }
 
+    fn histogram_with_flow(&self, grid: Grid<A>) -> Histogram<A> {
+        let mut histogram = Histogram::with_flow_tracking(grid);
+        for point in self.axis_iter(Axis(0)) {
+            let _ = histogram.add_observation(&point);
+        }
+        histogram
+    }
+
+    fn weighted_histogram<W>(
+        &self,
+        grid: Grid<A>,
+        weights: &ArrayRef<W, Ix1>,
+    ) -> Result<WeightedHistogram<A, W>, ShapeMismatch>
+    where
+        W: Clone + Zero + AddAssign + Mul<Output = W>,
+    {
+        if self.nrows() != weights.len() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: weights.shape().to_vec(),
+            });
+        }
+        let mut histogram = WeightedHistogram::new(grid);
+        for (point, weight) in self.axis_iter(Axis(0)).zip(weights) {
+            let _ = histogram.add_observation(&point, weight.clone());
+        }
+        Ok(histogram)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_histogram(&self, grid: Grid<A>) -> Histogram<A>
+    where
+        A: Ord + Send + Sync,
+    {
+        let shape = grid.shape();
+        let counts = self
+            .axis_chunks_iter(Axis(0), PAR_MIN_POINTS_PER_BLOCK)
+            .into_par_iter()
+            .fold(
+                || ArrayD::zeros(shape.clone()),
+                |mut counts, points| {
+                    for point in points.axis_iter(Axis(0)) {
+                        if let Some(bin_index) = grid.index_of(&point) {
+                            counts[&*bin_index] += 1;
+                        }
+                    }
+                    counts
+                },
+            )
+            .reduce(
+                || ArrayD::zeros(shape.clone()),
+                |mut counts, other_counts| {
+                    counts += &other_counts;
+                    counts
+                },
+            );
+        Histogram {
+            counts,
+            grid,
+            flow: None,
+        }
+    }
+
+    private_impl! {}
+}
+
+/// Extension trait for `ArrayRef` providing methods to compute histograms of floating point
+/// observations that may contain NaN values, without converting them to a not-NaN type first.
+///
+/// The histograms are defined over the corresponding not-NaN type, i.e. over
+/// [`N32`]/[`N64`] for `f32`/`f64` observations.
+///
+/// [`N32`]: https://docs.rs/noisy_float/latest/noisy_float/types/type.N32.html
+/// [`N64`]: https://docs.rs/noisy_float/latest/noisy_float/types/type.N64.html
+pub trait HistogramSkipNanExt<A>
+where
+    A: MaybeNan,
+{
+    /// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram) for a 2-dimensional
+    /// array of points `M`, skipping the points with NaN coordinates.
+    ///
+    /// See [`HistogramExt::histogram`] for more details on the layout of `M`.
+    ///
+    /// Important: points outside the grid are ignored!
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{
+    ///     histogram::{strategies::Sqrt, GridBuilder},
+    ///     HistogramSkipNanExt,
+    /// };
+    /// use noisy_float::types::N64;
+    ///
+    /// let observations = array![
+    ///     [1., 0.5],
+    ///     [-0.5, f64::NAN],
+    ///     [-1., -0.5],
+    ///     [0.5, -1.]
+    /// ];
+    /// let grid = GridBuilder::<Sqrt<N64>>::from_array_skipnan(&observations)
+    ///     .unwrap()
+    ///     .build();
+    /// let histogram = observations.histogram_skipnan(grid);
+    ///
+    /// assert_eq!(histogram.total_count(), 3);
+    /// ```
+    ///
+    /// [`HistogramExt::histogram`]: trait.HistogramExt.html#tymethod.histogram
+    fn histogram_skipnan(&self, grid: Grid<A::NotNan>) -> Histogram<A::NotNan>
+    where
+        A::NotNan: Ord;
+
+    /// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram) for a 2-dimensional
+    /// array of points `M`, keeping track of the points that fall outside the grid in its
+    /// [`FlowCounts`](struct.FlowCounts.html): points with NaN coordinates are counted as
+    /// [`unbinnable`](struct.FlowCounts.html#method.unbinnable), unless they fall outside
+    /// the grid along another axis.
+    ///
+    /// See [`HistogramExt::histogram`] for more details on the layout of `M`.
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    ///
+    /// [`HistogramExt::histogram`]: trait.HistogramExt.html#tymethod.histogram
+    fn histogram_with_flow_skipnan(&self, grid: Grid<A::NotNan>) -> Histogram<A::NotNan>
+    where
+        A::NotNan: Ord;
+
+    private_decl! {}
+}
+
+impl<A> HistogramSkipNanExt<A> for ArrayRef<A, Ix2>
+where
+    A: MaybeNan,
+{
+    fn histogram_skipnan(&self, grid: Grid<A::NotNan>) -> Histogram<A::NotNan>
+    where
+        A::NotNan: Ord,
+    {
+        let mut histogram = Histogram::new(grid);
+        for point in self.axis_iter(Axis(0)) {
+            let _ = histogram.add_observation_skipnan(&point);
+        }
+        histogram
+    }
+
+    fn histogram_with_flow_skipnan(&self, grid: Grid<A::NotNan>) -> Histogram<A::NotNan>
+    where
+        A::NotNan: Ord,
+    {
+        let mut histogram = Histogram::with_flow_tracking(grid);
+        for point in self.axis_iter(Axis(0)) {
+            let _ = histogram.add_observation_skipnan(&point);
+        }
+        histogram
+    }
+
     private_impl! {}
 }
+
+#[cfg(test)]
+mod histogram_tests {
+    use super::{Histogram, HistogramExt, HistogramSkipNanExt};
+    use crate::errors::{EmptyInput, QuantileError, ShapeMismatch};
+    use crate::histogram::{BinClosure, Bins, Edges, Grid};
+    use crate::CorrelationExt;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Axis};
+    use noisy_float::types::n64;
+
+    fn grid() -> Grid<i32> {
+        Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1, 3])),
+            Bins::new(Edges::from(vec![0, 2, 6])),
+        ])
+    }
+
+    fn other_grid() -> Grid<i32> {
+        Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1, 3])),
+            Bins::new(Edges::from(vec![0, 2, 5])),
+        ])
+    }
+
+    #[test]
+    fn merge_and_subtract() {
+        let mut histogram = array![[0, 0], [2, 3], [2, 3]].histogram(grid());
+        let other = array![[0, 4], [2, 3]].histogram(grid());
+        histogram.merge(&other).unwrap();
+        assert_eq!(histogram.counts(), array![[1, 1], [0, 3]].into_dyn());
+        assert_eq!(histogram.total_count(), 5);
+        histogram.subtract(&other).unwrap();
+        assert_eq!(histogram.counts(), array![[1, 0], [0, 2]].into_dyn());
+    }
+
+    #[test]
+    fn merge_and_subtract_require_the_same_grid() {
+        let mut histogram = array![[0, 0]].histogram(grid());
+        let other = array![[0, 0]].histogram(other_grid());
+        assert!(histogram.merge(&other).is_err());
+        assert!(histogram.subtract(&other).is_err());
+        assert_eq!(histogram.counts(), array![[1, 0], [0, 0]].into_dyn());
+    }
+
+    #[test]
+    #[should_panic]
+    fn subtract_panics_on_negative_counts() {
+        let mut histogram = array![[0, 0]].histogram(grid());
+        let other = array![[0, 0], [0, 0]].histogram(grid());
+        let _ = histogram.subtract(&other);
+    }
+
+    #[test]
+    fn scale() {
+        let mut histogram = array![[0, 0], [2, 3]].histogram(grid());
+        histogram.scale(3);
+        assert_eq!(histogram.counts(), array![[3, 0], [0, 3]].into_dyn());
+        assert_eq!(
+            histogram.scaled(0.25),
+            array![[0.75, 0.], [0., 0.75]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn probability_and_density() {
+        let histogram = array![[0, 0], [2, 3], [2, 4], [0, 5]].histogram(grid());
+        assert_eq!(
+            histogram.probability().unwrap(),
+            array![[0.25, 0.25], [0., 0.5]].into_dyn()
+        );
+        let density = histogram.density().unwrap();
+        // Bin volumes are [[2, 4], [4, 8]]
+        assert_eq!(density, array![[0.125, 0.0625], [0., 0.0625]].into_dyn());
+        let integral: f64 = (&density * &array![[2., 4.], [4., 8.]].into_dyn()).sum();
+        assert_abs_diff_eq!(integral, 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn normalizing_an_empty_histogram_fails() {
+        let histogram = Histogram::new(grid());
+        assert_eq!(histogram.probability(), Err(EmptyInput));
+        assert_eq!(histogram.density(), Err(EmptyInput));
+    }
+
+    #[test]
+    fn weighted_histogram_with_unit_weights_matches_histogram() {
+        let observations = array![[0, 0], [2, 3], [2, 4], [0, 5], [7, 7]];
+        let histogram = observations.histogram(grid());
+        let weighted = observations
+            .weighted_histogram(grid(), &array![1., 1., 1., 1., 1.])
+            .unwrap();
+        assert_eq!(
+            weighted.sum_of_weights(),
+            histogram.counts().mapv(|c| c as f64)
+        );
+        assert_eq!(weighted.sum_of_squared_weights(), weighted.sum_of_weights());
+        assert_eq!(
+            weighted.errors(),
+            histogram.counts().mapv(|c| (c as f64).sqrt())
+        );
+    }
+
+    #[test]
+    fn weighted_histogram_tracks_squared_weights() {
+        let observations = array![[0, 0], [0, 1], [2, 3]];
+        let weighted = observations
+            .weighted_histogram(grid(), &array![3., -1., 0.5])
+            .unwrap();
+        assert_eq!(
+            weighted.sum_of_weights(),
+            array![[2., 0.], [0., 0.5]].into_dyn()
+        );
+        assert_eq!(
+            weighted.sum_of_squared_weights(),
+            array![[10., 0.], [0., 0.25]].into_dyn()
+        );
+    }
+
+    #[test]
+    fn weighted_histogram_requires_one_weight_per_point() {
+        let observations = array![[0, 0], [0, 1], [2, 3]];
+        assert_eq!(
+            observations
+                .weighted_histogram(grid(), &array![1., 2.])
+                .err(),
+            Some(ShapeMismatch {
+                first_shape: vec![3, 2],
+                second_shape: vec![2],
+            })
+        );
+    }
+
+    #[test]
+    fn flow_counts_preserve_the_number_of_entries() {
+        let observations = array![[0, 0], [-1, 0], [3, 6], [-1, 7], [2, 5], [0, -3]];
+        let histogram = observations.histogram_with_flow(grid());
+        assert_eq!(histogram.counts(), array![[1, 0], [0, 1]].into_dyn());
+        let flow = histogram.flow().unwrap();
+        assert_eq!(flow.underflow(), &[2, 1]);
+        assert_eq!(flow.overflow(), &[1, 2]);
+        assert_eq!(flow.out_of_range(), 4);
+        assert_eq!(flow.unbinnable(), 0);
+        assert_eq!(histogram.entries(), observations.nrows());
+
+        let histogram = observations.histogram(grid());
+        assert!(histogram.flow().is_none());
+        assert_eq!(histogram.entries(), 2);
+    }
+
+    #[test]
+    fn observations_on_an_axis_without_edges_are_unbinnable() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1])),
+            Bins::new(Edges::from(vec![])),
+        ]);
+        let histogram = array![[0, 3], [0, 2], [5, 3]].histogram_with_flow(grid);
+        let flow = histogram.flow().unwrap();
+        assert_eq!(flow.underflow(), &[0, 0]);
+        assert_eq!(flow.overflow(), &[1, 0]);
+        assert_eq!(flow.out_of_range(), 1);
+        assert_eq!(flow.unbinnable(), 2);
+        assert_eq!(histogram.entries(), 3);
+    }
+
+    #[test]
+    fn flow_counts_follow_histogram_arithmetic() {
+        let mut histogram = array![[0, 0], [-1, 0], [3, 0]].histogram_with_flow(grid());
+        let other = array![[-1, 0]].histogram_with_flow(grid());
+        histogram.merge(&other).unwrap();
+        assert_eq!(histogram.flow().unwrap().underflow(), &[2, 0]);
+        histogram.scale(2);
+        assert_eq!(histogram.flow().unwrap().underflow(), &[4, 0]);
+        assert_eq!(histogram.flow().unwrap().overflow(), &[2, 0]);
+        histogram.subtract(&other).unwrap();
+        assert_eq!(histogram.flow().unwrap().underflow(), &[3, 0]);
+        assert_eq!(histogram.entries(), 7);
+
+        histogram.merge(&Histogram::new(grid())).unwrap();
+        assert!(histogram.flow().is_none());
+    }
+
+    fn grid_1d() -> Grid<i32> {
+        Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 8]))])
+    }
+
+    #[test]
+    fn cumulative_counts_along_each_axis() {
+        let histogram = array![[0, 0], [0, 3], [2, 1], [2, 3], [2, 5]].histogram(grid());
+        assert_eq!(histogram.counts(), array![[1, 1], [1, 2]].into_dyn());
+        assert_eq!(
+            histogram.cumulative_counts(Axis(0)),
+            array![[1, 1], [2, 3]].into_dyn()
+        );
+        assert_eq!(
+            histogram.cumulative_counts(Axis(1)),
+            array![[1, 2], [1, 3]].into_dyn()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn cumulative_counts_panics_on_out_of_bounds_axis() {
+        Histogram::new(grid()).cumulative_counts(Axis(2));
+    }
+
+    #[test]
+    fn cdf_interpolates_within_bins() {
+        // The middle bin is empty: the CDF is flat over it.
+        let histogram = array![[0], [1], [5], [7], [-1], [9]].histogram(grid_1d());
+        assert_eq!(histogram.cdf(&-5), Ok(0.));
+        assert_eq!(histogram.cdf(&0), Ok(0.));
+        assert_eq!(histogram.cdf(&1), Ok(0.25));
+        assert_eq!(histogram.cdf(&2), Ok(0.5));
+        assert_eq!(histogram.cdf(&3), Ok(0.5));
+        assert_eq!(histogram.cdf(&6), Ok(0.75));
+        assert_eq!(histogram.cdf(&8), Ok(1.));
+        assert_eq!(histogram.cdf(&100), Ok(1.));
+    }
+
+    #[test]
+    fn quantile_inverts_cdf() {
+        let histogram = array![[0], [1], [5], [7]].histogram(grid_1d());
+        assert_eq!(histogram.quantile(n64(0.)), Ok(0.));
+        assert_eq!(histogram.quantile(n64(0.25)), Ok(1.));
+        // The empty bin is skipped
+        assert_eq!(histogram.quantile(n64(0.5)), Ok(2.));
+        assert_eq!(histogram.quantile(n64(0.75)), Ok(6.));
+        assert_eq!(histogram.quantile(n64(1.)), Ok(8.));
+        for &value in &[1, 5, 6, 7] {
+            let q = histogram.cdf(&value).unwrap();
+            assert_abs_diff_eq!(
+                histogram.quantile(n64(q)).unwrap(),
+                f64::from(value),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn cdf_and_quantile_of_empty_histogram() {
+        let histogram = Histogram::new(grid_1d());
+        assert_eq!(histogram.cdf(&1), Err(EmptyInput));
+        assert_eq!(histogram.quantile(n64(0.5)), Err(QuantileError::EmptyInput));
+        assert_eq!(
+            array![[1]].histogram(grid_1d()).quantile(n64(1.5)),
+            Err(QuantileError::InvalidQuantile(n64(1.5)))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn cdf_panics_on_multidimensional_histogram() {
+        let _ = array![[0, 0]].histogram(grid()).cdf(&0);
+    }
+
+    #[test]
+    fn sum_axis_and_project_marginalize_counts() {
+        let histogram =
+            array![[0, 0], [0, 3], [2, 1], [2, 3], [2, 5], [7, 7]].histogram_with_flow(grid());
+        let marginal = histogram.sum_axis(Axis(1));
+        assert_eq!(marginal.counts(), array![2, 3].into_dyn());
+        assert_eq!(marginal.grid().projections(), &grid().projections()[..1]);
+        assert!(marginal.flow().is_none());
+        assert_eq!(
+            histogram.sum_axis(Axis(0)).counts(),
+            array![2, 3].into_dyn()
+        );
+
+        let transposed = histogram.project(&[Axis(1), Axis(0)]);
+        assert_eq!(transposed.counts(), histogram.counts().t());
+        assert_eq!(transposed.grid().projections()[0], grid().projections()[1]);
+        let projection = histogram.project(&[Axis(1)]);
+        assert_eq!(projection.counts(), histogram.sum_axis(Axis(0)).counts());
+        let projection = histogram.project(&[]);
+        assert_eq!(projection.ndim(), 0);
+        assert_eq!(projection.total_count(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn project_panics_on_duplicated_axes() {
+        Histogram::new(grid()).project(&[Axis(0), Axis(0)]);
+    }
+
+    #[test]
+    fn slice_keeps_selected_bins() {
+        let histogram = array![[0, 0], [0, 3], [2, 1], [2, 3], [2, 5]].histogram(grid());
+        let sliced = histogram.slice(&[1..2, 0..2]);
+        assert_eq!(sliced.counts(), array![[1, 2]].into_dyn());
+        assert_eq!(
+            sliced.grid(),
+            &Grid::from(vec![
+                Bins::new(Edges::from(vec![1, 3])),
+                Bins::new(Edges::from(vec![0, 2, 6])),
+            ])
+        );
+
+        let empty = histogram.slice(&[1..1, 0..2]);
+        assert_eq!(empty.counts().shape(), &[0, 2]);
+        assert_eq!(empty.grid().shape(), vec![0, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_panics_on_out_of_bounds_range() {
+        Histogram::new(grid()).slice(&[0..1, 1..3]);
+    }
+
+    #[test]
+    fn rebin_merges_adjacent_bins() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1, 2, 3, 4])),
+            Bins::new(Edges::from(vec![0, 10])),
+        ]);
+        let histogram =
+            array![[0, 0], [1, 5], [1, 5], [2, 1], [3, 3], [8, 0]].histogram_with_flow(grid);
+        let rebinned = histogram.rebin(Axis(0), 2);
+        assert_eq!(rebinned.counts(), array![[3], [2]].into_dyn());
+        assert_eq!(
+            rebinned.grid().projections()[0],
+            Bins::new(Edges::from(vec![0, 2, 4]))
+        );
+        assert_eq!(rebinned.flow(), histogram.flow());
+        assert_eq!(rebinned.entries(), histogram.entries());
+        let unchanged = histogram.rebin(Axis(1), 1);
+        assert_eq!(unchanged.counts(), histogram.counts());
+        assert_eq!(unchanged.grid(), histogram.grid());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rebin_panics_if_factor_does_not_divide_bins() {
+        Histogram::new(grid()).rebin(Axis(0), 3);
+    }
+
+    #[test]
+    fn histogram_skipnan_matches_not_nan_histogram() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![n64(0.), n64(1.), n64(3.)])),
+            Bins::new(Edges::from(vec![n64(0.), n64(2.), n64(6.)])),
+        ]);
+        let observations = array![
+            [0.5, 1.],
+            [f64::NAN, 1.],
+            [2., 5.],
+            [2., f64::NAN],
+            [9., 1.]
+        ];
+        let histogram = observations.histogram_skipnan(grid.clone());
+        let expected = array![[0.5, 1.], [2., 5.], [9., 1.]]
+            .mapv(n64)
+            .histogram(grid);
+        assert_eq!(histogram.counts(), expected.counts());
+        assert!(histogram.flow().is_none());
+    }
+
+    #[test]
+    fn nan_observations_are_unbinnable() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![n64(0.), n64(1.), n64(3.)])),
+            Bins::new(Edges::from(vec![n64(0.), n64(2.), n64(6.)])),
+        ]);
+        let observations = array![
+            [0.5, 1.],
+            [f64::NAN, 1.],
+            [f64::NAN, f64::NAN],
+            [f64::NAN, -1.],
+            [9., 1.]
+        ];
+        let histogram = observations.histogram_with_flow_skipnan(grid);
+        let flow = histogram.flow().unwrap();
+        assert_eq!(flow.unbinnable(), 2);
+        assert_eq!(flow.out_of_range(), 2);
+        assert_eq!(flow.underflow(), &[0, 1]);
+        assert_eq!(flow.overflow(), &[1, 0]);
+        assert_eq!(histogram.total_count(), 1);
+        assert_eq!(histogram.entries(), 5);
+    }
+
+    #[test]
+    fn closure_policies_decide_the_flow_of_edge_values() {
+        let observations = array![[0], [2], [8], [9], [-1]];
+
+        let bins = Bins::new(Edges::from(vec![0, 2, 4, 8])).with_closure(BinClosure::LeftOpen);
+        let histogram = observations.histogram_with_flow(Grid::from(vec![bins]));
+        assert_eq!(histogram.counts(), array![1, 0, 1].into_dyn());
+        let flow = histogram.flow().unwrap();
+        assert_eq!(flow.underflow(), &[2]);
+        assert_eq!(flow.overflow(), &[1]);
+        assert_eq!(histogram.cdf(&0), Ok(0.));
+        assert_eq!(histogram.cdf(&2), Ok(0.5));
+        assert_eq!(histogram.cdf(&8), Ok(1.));
+
+        let bins = Bins::new(Edges::from(vec![0, 2, 4, 8])).with_closure(BinClosure::LastBinClosed);
+        let histogram = observations.histogram_with_flow(Grid::from(vec![bins]));
+        assert_eq!(histogram.counts(), array![1, 1, 1].into_dyn());
+        let flow = histogram.flow().unwrap();
+        assert_eq!(flow.underflow(), &[1]);
+        assert_eq!(flow.overflow(), &[1]);
+    }
+
+    #[test]
+    fn slice_and_rebin_keep_the_closure() {
+        let bins = Bins::new(Edges::from(vec![0, 1, 2, 3, 4])).with_closure(BinClosure::LeftOpen);
+        let histogram = Histogram::new(Grid::from(vec![bins, Bins::new(Edges::from(vec![0, 1]))]));
+        let sliced = histogram.slice(&[1..3, 0..1]);
+        assert_eq!(
+            sliced.grid().projections()[0].closure(),
+            BinClosure::LeftOpen
+        );
+        let rebinned = histogram.rebin(Axis(0), 2);
+        assert_eq!(
+            rebinned.grid().projections()[0].closure(),
+            BinClosure::LeftOpen
+        );
+    }
+
+    #[test]
+    fn slice_opens_the_last_bin_closed_only_if_it_is_discarded() {
+        let bins =
+            Bins::new(Edges::from(vec![0, 1, 2, 3, 4])).with_closure(BinClosure::LastBinClosed);
+        let histogram = Histogram::new(Grid::from(vec![bins.clone(), bins]));
+        let sliced = histogram.slice(&[1..3, 2..4]);
+        assert_eq!(
+            sliced.grid().projections()[0].closure(),
+            BinClosure::RightOpen
+        );
+        assert_eq!(
+            sliced.grid().projections()[1].closure(),
+            BinClosure::LastBinClosed
+        );
+        // 3 belonged to the next bin of the original histogram, 4 to its last bin
+        let observations = array![[1, 2], [2, 2], [3, 2], [1, 4]];
+        let histogram = observations.histogram(sliced.grid().clone());
+        assert_eq!(histogram.counts(), array![[1, 1], [1, 0]].into_dyn());
+    }
+
+    #[test]
+    fn statistics_use_bin_centers() {
+        // The observations are binned as if they were their bin centers
+        let observations = array![[0, 0], [0, 3], [2, 1], [2, 3], [2, 5], [1, 5]];
+        let centers = array![[0.5, 1.], [0.5, 4.], [2., 1.], [2., 4.], [2., 4.], [2., 4.]];
+        let histogram = observations.histogram(grid());
+
+        assert_abs_diff_eq!(
+            histogram.mean().unwrap(),
+            centers.mean_axis(Axis(0)).unwrap()
+        );
+        assert_abs_diff_eq!(
+            histogram.var(1.).unwrap(),
+            centers.var_axis(Axis(0), 1.),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            histogram.cov(1.).unwrap(),
+            centers.t().cov(1.).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            histogram.cov(0.).unwrap().diag(),
+            histogram.var(0.).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn statistics_of_empty_histograms_are_errors() {
+        let histogram = Histogram::new(grid());
+        assert_eq!(histogram.mean(), Err(EmptyInput));
+        assert_eq!(histogram.var(0.), Err(EmptyInput));
+        assert_eq!(histogram.cov(0.), Err(EmptyInput));
+        assert_eq!(histogram.mode(), Err(EmptyInput));
+        assert_eq!(histogram.median_axis(Axis(1)), Err(EmptyInput));
+        assert_eq!(
+            histogram.quantile_axis(Axis(0), n64(0.5)),
+            Err(QuantileError::EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`ddof` needs to be non-negative")]
+    fn var_panics_if_ddof_is_too_large() {
+        let histogram = array![[0, 0], [2, 5]].histogram(grid());
+        let _ = histogram.var(2.);
+    }
+
+    #[test]
+    fn mode_picks_the_first_most_populated_bin() {
+        let histogram = array![[2, 5], [0, 3], [2, 1], [0, 4]].histogram(grid());
+        assert_eq!(histogram.mode(), Ok(vec![0, 1]));
+        let histogram = array![[2, 5], [0, 3], [2, 4]].histogram(grid());
+        assert_eq!(histogram.mode(), Ok(vec![1, 1]));
+    }
+
+    #[test]
+    fn quantiles_along_each_axis() {
+        let histogram = array![[0, 0], [0, 3], [2, 1], [2, 3], [2, 5]].histogram(grid());
+        // Along the first axis, the bins [0, 1) and [1, 3) hold 2 and 3 observations
+        assert_eq!(histogram.quantile_axis(Axis(0), n64(0.4)), Ok(1.));
+        assert_eq!(histogram.median_axis(Axis(0)), Ok(1. + 2. / 6.));
+        // Along the second axis, the bins [0, 2) and [2, 6) hold 2 and 3 observations
+        assert_eq!(histogram.median_axis(Axis(1)), Ok(2. + 4. / 6.));
+        assert_eq!(
+            histogram.quantile_axis(Axis(1), n64(1.5)),
+            Err(QuantileError::InvalidQuantile(n64(1.5)))
+        );
+
+        let histogram_1d = array![[0], [1], [5], [7], [-1], [9]].histogram(grid_1d());
+        assert_eq!(
+            histogram_1d.quantile(n64(0.3)),
+            histogram_1d.quantile_axis(Axis(0), n64(0.3))
+        );
+    }
+}
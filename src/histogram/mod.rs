@@ -1,10 +1,13 @@
 //! Histogram functionalities.
-pub use self::bins::{Bins, Edges};
-pub use self::grid::{Grid, GridBuilder};
-pub use self::histograms::{Histogram, HistogramExt};
+pub use self::bins::{BinClosure, Bins, Edges};
+pub use self::grid::{BinsSpec, Grid, GridBuilder};
+pub use self::histograms::{
+    FlowCounts, Histogram, HistogramExt, HistogramSkipNanExt, WeightedHistogram,
+};
 
 mod bins;
 pub mod errors;
+mod export;
 mod grid;
 mod histograms;
 pub mod strategies;
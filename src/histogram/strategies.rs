@@ -7,21 +7,44 @@
 //! For those strategies that prescribe the optimal number of [`Bins`], the optimal bin width is
 //! computed by `bin_width = (max - min)/n`.
 //!
-//! Since all bins are left-closed and right-open, it is guaranteed to add an extra bin to include
+//! Since evenly spaced bins are left-closed and right-open, it is guaranteed to add an extra bin to include
 //! the maximum value from the given data when necessary, so that no data is discarded.
 //!
+//! [`Scott`], [`Doane`] and [`Stone`] build the same bins as [`NumPy`] instead: given their
+//! estimated `bin_width`, they split the range of the data in `ceil((max - min) / bin_width)`
+//! bins of equal width, whose last edge is exactly `max`, and the last bin is closed on the
+//! right (see [`BinClosure::LastBinClosed`]).
+//!
+//! Most strategies build evenly spaced bins. [`Scott`], [`Doane`] and [`Stone`] find the bin
+//! containing an observation in constant time (see [`Bins::with_uniform_lookup`]); [`Sqrt`],
+//! [`Rice`], [`Sturges`], [`FreedmanDiaconis`] and [`Auto`] do not require the edges to be
+//! `ToPrimitive` and perform a binary search, unless [`Grid::into_uniform_lookup`] is called on
+//! the grid built from them. [`LogSpaced`], [`EqualFrequency`]
+//! and [`BayesianBlocks`] build bins of variable width instead, whose edges span exactly the
+//! range of the data: their last bin is closed on the right to include the maximum value.
+//!
 //! # Strategies
 //!
 //! Currently, the following strategies are implemented:
 //!
 //! - [`Auto`]: Maximum of the [`Sturges`] and [`FreedmanDiaconis`] strategies. Provides good all
 //!   around performance.
+//! - [`BayesianBlocks`]: Adaptive bins, whose edges are the change points of the optimal
+//!   piecewise constant density. Follows the data closely, at a quadratic cost.
+//! - [`Doane`]: An improved version of Sturges’ estimator that works better with non-normal
+//!   datasets.
+//! - [`EqualFrequency`]: Bins whose edges are quantiles of the data, holding approximately the
+//!   same number of observations.
 //! - [`FreedmanDiaconis`]: Robust (resilient to outliers) strategy that takes into account data
 //!   variability and data size.
+//! - [`LogSpaced`]: Logarithmically spaced bins, for strictly positive and heavy-tailed data.
 //! - [`Rice`]: A strategy that does not take variability into account, only data size. Commonly
 //!   overestimates number of bins required.
+//! - [`Scott`]: Less robust estimator that takes into account data variability and data size.
 //! - [`Sqrt`]: Square root (of data size) strategy, used by Excel and other programs
 //!   for its speed and simplicity.
+//! - [`Stone`]: Estimator based on leave-one-out cross-validation estimate of the integrated
+//!   squared error. Can be regarded as a generalization of Scott’s rule.
 //! - [`Sturges`]: R’s default strategy, only accounts for data size. Only optimal for gaussian data
 //!   and underestimates number of bins for large non-gaussian datasets.
 //!
@@ -36,23 +59,32 @@
 //!
 //! [`GridBuilder`]: ../struct.GridBuilder.html
 //! [`Bins`]: ../struct.Bins.html
+//! [`Bins::with_uniform_lookup`]: ../struct.Bins.html#method.with_uniform_lookup
+//! [`BinClosure::LastBinClosed`]: ../enum.BinClosure.html#variant.LastBinClosed
 //! [`Grid`]: ../struct.Grid.html
+//! [`Grid::into_uniform_lookup`]: ../struct.Grid.html#method.into_uniform_lookup
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy/reference/generated/numpy.histogram_bin_edges.html#numpy.histogram_bin_edges
 //! [`Auto`]: struct.Auto.html
 //! [`Sturges`]: struct.Sturges.html
 //! [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
 //! [`Rice`]: struct.Rice.html
 //! [`Sqrt`]: struct.Sqrt.html
+//! [`Scott`]: struct.Scott.html
+//! [`Doane`]: struct.Doane.html
+//! [`Stone`]: struct.Stone.html
+//! [`LogSpaced`]: struct.LogSpaced.html
+//! [`EqualFrequency`]: struct.EqualFrequency.html
+//! [`BayesianBlocks`]: struct.BayesianBlocks.html
 //! [iqr]: https://www.wikiwand.com/en/Interquartile_range
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 
 use crate::{
-    histogram::{errors::BinsBuildError, Bins, Edges},
+    histogram::{errors::BinsBuildError, BinClosure, Bins, Edges},
     quantile::{interpolate::Nearest, Quantile1dExt, QuantileExt},
 };
 use ndarray::prelude::*;
 use noisy_float::types::n64;
-use num_traits::{FromPrimitive, NumOps, Zero};
+use num_traits::{FromPrimitive, NumOps, ToPrimitive, Zero};
 
 /// A trait implemented by all strategies to build [`Bins`] with parameters inferred from
 /// observations.
@@ -180,6 +212,85 @@ pub struct FreedmanDiaconis<T> {
     builder: EquiSpaced<T>,
 }
 
+/// Less robust estimator that takes into account data variability and data size.
+///
+/// Let `n` be the number of observations and `σ` their standard deviation.
+///
+/// `bin_width` = (24 × √π / `n`)<sup>1/3</sup> × `σ`
+///
+/// The bin width is proportional to the standard deviation of the data and inversely
+/// proportional to cube root of `n`. It can be too conservative for small datasets, but it is
+/// quite good for large datasets. The standard deviation is not very robust to outliers.
+/// Values are very similar to the [`FreedmanDiaconis`] estimator in the absence of outliers.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`FreedmanDiaconis`]: struct.FreedmanDiaconis.html
+#[derive(Debug)]
+pub struct Scott<T> {
+    builder: VariableWidth<T>,
+    bin_width: T,
+}
+
+/// An improved version of Sturges’ formula that produces better estimates for non-normal
+/// datasets.
+///
+/// Let `n` be the number of observations, `g₁` their estimated third-moment skewness and
+/// `σ_g₁` the standard deviation of `g₁` for normally distributed data. Then
+///
+/// `n_bins` = 1 + log<sub>2</sub>(`n`) + log<sub>2</sub>(1 + |`g₁`| / `σ_g₁`)
+///
+/// where
+///
+/// `σ_g₁` = √(6 × (`n` - 2) / ((`n` + 1) × (`n` + 3)))
+///
+/// As in [`NumPy`], `n_bins` is not rounded: the bin width is `(max - min) / n_bins`, and the
+/// number of bins actually built is `ceil(n_bins)`.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - having more than 2 observations
+/// - not being constant
+///
+/// [`NumPy`]: https://numpy.org/doc/stable/reference/generated/numpy.histogram_bin_edges.html
+#[derive(Debug)]
+pub struct Doane<T> {
+    builder: VariableWidth<T>,
+    bin_width: T,
+}
+
+/// Estimator based on leave-one-out cross-validation estimate of the integrated squared error.
+/// Can be regarded as a generalization of Scott’s rule.
+///
+/// Let `n` be the number of observations and `p_k` the fraction of observations falling in the
+/// `k`-th of `n_bins` bins of width `h = (max - min) / n_bins`. The number of bins minimizes
+///
+/// `J(n_bins)` = (2 - (`n` + 1) × Σ<sub>k</sub> `p_k`<sup>2</sup>) / `h`
+///
+/// among all the values between 1 and max(100, √`n`), bounds included. As in [`NumPy`], the
+/// bin width is then `(max - min) / n_bins`.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - having more than 1 observation
+/// - not being constant
+///
+/// [`NumPy`]: https://numpy.org/doc/stable/reference/generated/numpy.histogram_bin_edges.html
+#[derive(Debug)]
+pub struct Stone<T> {
+    builder: VariableWidth<T>,
+    bin_width: T,
+}
+
 #[derive(Debug)]
 enum SturgesOrFD<T> {
     Sturges(Sturges<T>),
@@ -210,6 +321,123 @@ pub struct Auto<T> {
     builder: SturgesOrFD<T>,
 }
 
+#[derive(Debug)]
+struct VariableWidth<T> {
+    edges: Vec<T>,
+}
+
+/// Logarithmically spaced bins, suited to positive and heavy-tailed data such as latencies.
+///
+/// The edges are evenly spaced on a logarithmic scale between the minimum and the maximum of
+/// the observations: the ratio between consecutive edges is constant. When built with
+/// [`from_array`], the number of bins is chosen by applying the [`Auto`] strategy to the
+/// logarithm of the observations; [`with_n_bins`] sets it explicitly.
+///
+/// Edges other than the minimum and the maximum are converted from `f64` with
+/// [`FromPrimitive`]: for integers, this may merge some of the smallest bins.
+///
+/// The last bin is closed on the right (see [`BinClosure::LastBinClosed`]), so that the maximum
+/// is included without adding an extra bin.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray_stats::{
+///     histogram::{strategies::LogSpaced, GridBuilder},
+///     HistogramExt,
+/// };
+/// use noisy_float::types::n64;
+///
+/// let latencies = array![[0.8], [1.2], [2.5], [3.], [9.], [40.], [150.], [800.]].mapv(n64);
+/// let grid = GridBuilder::<LogSpaced<_>>::from_array(&latencies).unwrap().build();
+/// let histogram = latencies.histogram(grid);
+/// assert_eq!(histogram.total_count(), 8);
+/// ```
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+/// - being strictly positive
+///
+/// [`from_array`]: trait.BinsBuildingStrategy.html#tymethod.from_array
+/// [`with_n_bins`]: #method.with_n_bins
+/// [`Auto`]: struct.Auto.html
+/// [`FromPrimitive`]: https://docs.rs/num-traits/*/num_traits/cast/trait.FromPrimitive.html
+/// [`BinClosure::LastBinClosed`]: ../enum.BinClosure.html#variant.LastBinClosed
+#[derive(Debug)]
+pub struct LogSpaced<T> {
+    builder: VariableWidth<T>,
+}
+
+/// Equal-frequency bins, whose edges are quantiles of the observations.
+///
+/// Each bin holds approximately the same number of observations, which makes the bins narrow
+/// where data is dense and wide in the tails. When built with [`from_array`], the number of bins
+/// is the one of the [`Sturges`] strategy; [`with_n_bins`] sets it explicitly.
+///
+/// The edges are computed with [`quantiles_mut`] and the [`Nearest`] interpolation strategy, so
+/// that they are all observed values. Repeated quantiles are merged: the resulting number of
+/// bins can be lower than requested for data with many ties.
+///
+/// The last bin is closed on the right (see [`BinClosure::LastBinClosed`]), so that the maximum
+/// is included without adding an extra bin.
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [`from_array`]: trait.BinsBuildingStrategy.html#tymethod.from_array
+/// [`with_n_bins`]: #method.with_n_bins
+/// [`Sturges`]: struct.Sturges.html
+/// [`quantiles_mut`]: ../../trait.Quantile1dExt.html#tymethod.quantiles_mut
+/// [`Nearest`]: ../../interpolate/struct.Nearest.html
+/// [`BinClosure::LastBinClosed`]: ../enum.BinClosure.html#variant.LastBinClosed
+#[derive(Debug)]
+pub struct EqualFrequency<T> {
+    builder: VariableWidth<T>,
+}
+
+/// Adaptive bins given by the Bayesian blocks algorithm of [Scargle et al. (2013)][scargle].
+///
+/// The observations are treated as events, and the edges are the change points of the piecewise
+/// constant density maximizing the sum of the block fitnesses
+///
+/// `F(block)` = `N_k` × (ln(`N_k`) - ln(`T_k`)) - `ncp_prior`
+///
+/// where `N_k` is the number of observations in the block, `T_k` its length and
+///
+/// `ncp_prior` = 4 - ln(73.53 × `p₀` × `n`<sup>-0.478</sup>)
+///
+/// penalizes the number of blocks, with `n` the number of distinct observations and a false
+/// alarm probability `p₀` of 0.05. The optimal partition is found by dynamic programming, in
+/// `O(n²)` time.
+///
+/// As in [`astropy`], candidate change points lie between consecutive distinct observations;
+/// each edge is then set to the first observation on its right, so that edges are observed
+/// values. The last bin is closed on the right (see [`BinClosure::LastBinClosed`]).
+///
+/// # Notes
+///
+/// This strategy requires the data
+///
+/// - not being empty
+/// - not being constant
+///
+/// [scargle]: https://arxiv.org/abs/1207.5578
+/// [`astropy`]: https://docs.astropy.org/en/stable/api/astropy.stats.bayesian_blocks.html
+/// [`BinClosure::LastBinClosed`]: ../enum.BinClosure.html#variant.LastBinClosed
+#[derive(Debug)]
+pub struct BayesianBlocks<T> {
+    builder: VariableWidth<T>,
+}
+
 impl<T> EquiSpaced<T>
 where
     T: Ord + Clone + FromPrimitive + NumOps + Zero,
@@ -436,6 +664,184 @@ where
     }
 }
 
+impl<T> BinsBuildingStrategy for Scott<T>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        let min = a.min()?;
+        let max = a.max()?;
+        let values = to_f64_values(a);
+        // casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+        // but it's not relevant here
+        #[allow(clippy::cast_precision_loss)]
+        let n_elems = a.len() as f64;
+        let std = values.std(0.);
+        let bin_width = (24. * std::f64::consts::PI.sqrt() / n_elems).powf(1. / 3.) * std;
+        let builder = numpy_edges(min, max, bin_width)?;
+        let bin_width = T::from_f64(bin_width).ok_or(BinsBuildError::Strategy)?;
+        Ok(Self { builder, bin_width })
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build().into_uniform_lookup()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> Scott<T>
+where
+    T: Ord + Clone + FromPrimitive + NumOps + Zero,
+{
+    /// The bin width (or bin length) according to the fitted strategy.
+    pub fn bin_width(&self) -> T {
+        self.bin_width.clone()
+    }
+}
+
+impl<T> BinsBuildingStrategy for Doane<T>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant or if `a.len()<=2`.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        let min = a.min()?;
+        let max = a.max()?;
+        if a.len() <= 2 {
+            return Err(BinsBuildError::Strategy);
+        }
+        let values = to_f64_values(a);
+        // casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+        // but it's not relevant here
+        #[allow(clippy::cast_precision_loss)]
+        let n_elems = a.len() as f64;
+        let mean = values.sum() / n_elems;
+        let std = values.std(0.);
+        if std <= 0. {
+            return Err(BinsBuildError::Strategy);
+        }
+        let skewness = values.mapv(|x| ((x - mean) / std).powi(3)).sum() / n_elems;
+        let skewness_std = (6. * (n_elems - 2.) / ((n_elems + 1.) * (n_elems + 3.))).sqrt();
+        let n_bins = 1. + n_elems.log2() + (1. + skewness.abs() / skewness_std).log2();
+        let bin_width = (to_f64(max) - to_f64(min)) / n_bins;
+        let builder = numpy_edges(min, max, bin_width)?;
+        let bin_width = T::from_f64(bin_width).ok_or(BinsBuildError::Strategy)?;
+        Ok(Self { builder, bin_width })
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build().into_uniform_lookup()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> Doane<T>
+where
+    T: Ord + Clone + FromPrimitive + NumOps + Zero,
+{
+    /// The bin width (or bin length) according to the fitted strategy.
+    pub fn bin_width(&self) -> T {
+        self.bin_width.clone()
+    }
+}
+
+impl<T> BinsBuildingStrategy for Stone<T>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive + NumOps + Zero,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant or if `a.len()==1`.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        let min = a.min()?;
+        let max = a.max()?;
+        let n_elems = a.len();
+        if n_elems <= 1 {
+            return Err(BinsBuildError::Strategy);
+        }
+        let (min_value, max_value) = (to_f64(min), to_f64(max));
+        let range = max_value - min_value;
+        if range <= 0. {
+            return Err(BinsBuildError::Strategy);
+        }
+        let values = to_f64_values(a);
+        // casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+        // but it's not relevant here
+        #[allow(clippy::cast_precision_loss)]
+        let n = n_elems as f64;
+        // casting the floored square root from `f64` to `usize` is safe
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let max_n_bins = 100.max(n.sqrt() as usize);
+        // Leave-one-out cross-validation estimate of the integrated squared error
+        let cost = |n_bins: usize| {
+            #[allow(clippy::cast_precision_loss)]
+            let n_bins_f64 = n_bins as f64;
+            let mut counts = vec![0_usize; n_bins];
+            for &value in &values {
+                // As in NumPy, the last bin is closed on the right
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = ((value - min_value) / range * n_bins_f64) as usize;
+                counts[index.min(n_bins - 1)] += 1;
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let sum_of_squares = counts
+                .iter()
+                .map(|&count| (count as f64 / n).powi(2))
+                .sum::<f64>();
+            (2. - (n + 1.) * sum_of_squares) / (range / n_bins_f64)
+        };
+        let mut n_bins = 1;
+        let mut min_cost = cost(1);
+        for candidate in 2..=max_n_bins {
+            let candidate_cost = cost(candidate);
+            if candidate_cost < min_cost {
+                n_bins = candidate;
+                min_cost = candidate_cost;
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let bin_width = range / n_bins as f64;
+        let builder = numpy_edges(min, max, bin_width)?;
+        let bin_width = T::from_f64(bin_width).ok_or(BinsBuildError::Strategy)?;
+        Ok(Self { builder, bin_width })
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build().into_uniform_lookup()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> Stone<T>
+where
+    T: Ord + Clone + FromPrimitive + NumOps + Zero,
+{
+    /// The bin width (or bin length) according to the fitted strategy.
+    pub fn bin_width(&self) -> T {
+        self.bin_width.clone()
+    }
+}
+
 impl<T> BinsBuildingStrategy for Auto<T>
 where
     T: Ord + Clone + FromPrimitive + NumOps + Zero,
@@ -500,6 +906,271 @@ where
     }
 }
 
+impl<T> VariableWidth<T>
+where
+    T: Ord + Clone,
+{
+    /// Returns `Err(BinsBuildError::Strategy)` if there are less than two distinct edges.
+    /// Returns `Ok(Self)` otherwise.
+    fn new(edges: Vec<T>) -> Result<Self, BinsBuildError> {
+        let edges = Edges::from(edges).as_array_view().to_vec();
+        if edges.len() < 2 {
+            Err(BinsBuildError::Strategy)
+        } else {
+            Ok(Self { edges })
+        }
+    }
+
+    /// Returns bins whose last one is closed on the right, so that the last edge is included.
+    fn build(&self) -> Bins<T> {
+        Bins::new(Edges::from(self.edges.clone())).with_closure(BinClosure::LastBinClosed)
+    }
+
+    fn n_bins(&self) -> usize {
+        self.edges.len() - 1
+    }
+
+    fn edges(&self) -> &[T] {
+        &self.edges
+    }
+}
+
+impl<T> BinsBuildingStrategy for LogSpaced<T>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant, if it contains a value
+    /// that is not strictly positive or if the [`Auto`] strategy fails on the logarithm of the
+    /// observations.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    ///
+    /// [`Auto`]: struct.Auto.html
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        let (min, max) = LogSpaced::log_range(a)?;
+        let log_values = a.mapv(|value| n64(to_f64(&value).ln()));
+        let auto = Auto::from_array(&log_values)?;
+        let n_bins = ((max - min) / auto.bin_width().raw()).ceil();
+        // `n_bins` is a positive integer, small enough for the edges to fit in memory
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self::with_n_bins(a, (n_bins as usize).max(1))
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> LogSpaced<T>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive,
+{
+    /// Returns a strategy building `n_bins` logarithmically spaced bins between the minimum and
+    /// the maximum of `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(BinsBuildError::Strategy)` if `n_bins==0`, if the array is constant or if it
+    /// contains a value that is not strictly positive.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    pub fn with_n_bins(a: &ArrayRef<T, Ix1>, n_bins: usize) -> Result<Self, BinsBuildError> {
+        let (log_min, log_max) = LogSpaced::log_range(a)?;
+        if n_bins == 0 {
+            return Err(BinsBuildError::Strategy);
+        }
+        let mut edges = vec![a.min()?.clone(), a.max()?.clone()];
+        #[allow(clippy::cast_precision_loss)]
+        let log_width = (log_max - log_min) / n_bins as f64;
+        for i in 1..n_bins {
+            #[allow(clippy::cast_precision_loss)]
+            let edge = (log_min + i as f64 * log_width).exp();
+            edges.push(T::from_f64(edge).ok_or(BinsBuildError::Strategy)?);
+        }
+        let builder = VariableWidth::new(edges)?;
+        Ok(Self { builder })
+    }
+
+    /// Returns the natural logarithms of the minimum and the maximum of `a`, checking that the
+    /// observations are strictly positive and not constant.
+    fn log_range(a: &ArrayRef<T, Ix1>) -> Result<(f64, f64), BinsBuildError> {
+        let (min, max) = (to_f64(a.min()?), to_f64(a.max()?));
+        if min <= 0. || min >= max {
+            return Err(BinsBuildError::Strategy);
+        }
+        Ok((min.ln(), max.ln()))
+    }
+
+    /// The edges of the bins according to the fitted strategy, in increasing order.
+    #[must_use]
+    pub fn edges(&self) -> &[T] {
+        self.builder.edges()
+    }
+}
+
+impl<T> BinsBuildingStrategy for EqualFrequency<T>
+where
+    T: Ord + Clone,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        let n_elems = a.len();
+        // casting `n_elems: usize` to `f64` may casus off-by-one error here if `n_elems` > 2 ^ 53,
+        // but it's not relevant here
+        #[allow(clippy::cast_precision_loss)]
+        // casting the rounded base-2 log from `f64` to `usize` is safe
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let n_bins = (n_elems as f64).log2().round() as usize + 1;
+        Self::with_n_bins(a, n_bins)
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> EqualFrequency<T>
+where
+    T: Ord + Clone,
+{
+    /// Returns a strategy building (at most) `n_bins` bins holding approximately the same number
+    /// of observations of `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(BinsBuildError::Strategy)` if `n_bins==0` or if the array is constant.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    pub fn with_n_bins(a: &ArrayRef<T, Ix1>, n_bins: usize) -> Result<Self, BinsBuildError> {
+        if a.is_empty() {
+            return Err(BinsBuildError::EmptyInput);
+        }
+        if n_bins == 0 {
+            return Err(BinsBuildError::Strategy);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let qs = Array1::from_shape_fn(n_bins + 1, |i| n64(i as f64 / n_bins as f64));
+        let edges = a
+            .to_owned()
+            .quantiles_mut(&qs, &Nearest)
+            .map_err(|_| BinsBuildError::Strategy)?;
+        let builder = VariableWidth::new(edges.to_vec())?;
+        Ok(Self { builder })
+    }
+
+    /// The edges of the bins according to the fitted strategy, in increasing order.
+    #[must_use]
+    pub fn edges(&self) -> &[T] {
+        self.builder.edges()
+    }
+}
+
+impl<T> BinsBuildingStrategy for BayesianBlocks<T>
+where
+    T: Ord + Clone + ToPrimitive,
+{
+    type Elem = T;
+
+    /// Returns `Err(BinsBuildError::Strategy)` if the array is constant.
+    /// Returns `Err(BinsBuildError::EmptyInput)` if `a.len()==0`.
+    /// Returns `Ok(Self)` otherwise.
+    fn from_array(a: &ArrayRef<T, Ix1>) -> Result<Self, BinsBuildError> {
+        if a.is_empty() {
+            return Err(BinsBuildError::EmptyInput);
+        }
+        // Distinct observations, in increasing order, with their number of occurrences
+        let mut sorted = a.to_vec();
+        sorted.sort_unstable();
+        let mut values: Vec<T> = Vec::new();
+        let mut counts: Vec<f64> = Vec::new();
+        for value in sorted {
+            if values.last() == Some(&value) {
+                *counts.last_mut().unwrap() += 1.;
+            } else {
+                values.push(value);
+                counts.push(1.);
+            }
+        }
+        let n = values.len();
+        if n < 2 {
+            return Err(BinsBuildError::Strategy);
+        }
+        let t = values.iter().map(to_f64).collect::<Vec<_>>();
+        // Candidate change points: the first observation, the midpoints between consecutive
+        // observations and the last observation
+        let mut candidates = Vec::with_capacity(n + 1);
+        candidates.push(t[0]);
+        candidates.extend(t.windows(2).map(|w| 0.5 * (w[0] + w[1])));
+        candidates.push(t[n - 1]);
+        #[allow(clippy::cast_precision_loss)]
+        let ncp_prior = 4. - (73.53 * 0.05 * (n as f64).powf(-0.478)).ln();
+
+        // `best[r]` is the fitness of the optimal partition of the first `r + 1` observations,
+        // whose last block starts at observation `last[r]`
+        let mut best = vec![0_f64; n];
+        let mut last = vec![0_usize; n];
+        for r in 0..n {
+            let mut n_k = 0.;
+            let mut best_fitness = f64::NEG_INFINITY;
+            for start in (0..=r).rev() {
+                n_k += counts[start];
+                let t_k = candidates[r + 1] - candidates[start];
+                let mut fitness = n_k * (n_k.ln() - t_k.ln()) - ncp_prior;
+                if start > 0 {
+                    fitness += best[start - 1];
+                }
+                // Ties are broken in favour of the earliest start, as `argmax` does
+                if fitness >= best_fitness {
+                    best_fitness = fitness;
+                    last[r] = start;
+                }
+            }
+            best[r] = best_fitness;
+        }
+
+        let mut edges = vec![values[n - 1].clone()];
+        let mut end = n;
+        while end > 0 {
+            let start = last[end - 1];
+            edges.push(values[start].clone());
+            end = start;
+        }
+        let builder = VariableWidth::new(edges)?;
+        Ok(Self { builder })
+    }
+
+    fn build(&self) -> Bins<T> {
+        self.builder.build()
+    }
+
+    fn n_bins(&self) -> usize {
+        self.builder.n_bins()
+    }
+}
+
+impl<T> BayesianBlocks<T>
+where
+    T: Ord + Clone,
+{
+    /// The edges of the bins according to the fitted strategy, in increasing order.
+    #[must_use]
+    pub fn edges(&self) -> &[T] {
+        self.builder.edges()
+    }
+}
+
 /// Returns the `bin_width`, given the two end points of a range (`max`, `min`), and the number of
 /// bins, consuming endpoints
 ///
@@ -514,6 +1185,51 @@ where
     range / T::from_usize(n_bins).unwrap()
 }
 
+/// Returns the edges built by `NumPy` for an estimated `bin_width`: `ceil((max - min) / bin_width)`
+/// evenly spaced bins between `min` and `max`, bounds included.
+///
+/// Returns `Err(BinsBuildError::Strategy)` if `bin_width` is not positive or if `min >= max`.
+fn numpy_edges<T>(min: &T, max: &T, bin_width: f64) -> Result<VariableWidth<T>, BinsBuildError>
+where
+    T: Ord + Clone + FromPrimitive + ToPrimitive,
+{
+    let (first_edge, last_edge) = (to_f64(min), to_f64(max));
+    let range = last_edge - first_edge;
+    let n_bins = (range / bin_width).ceil();
+    if !(n_bins.is_finite() && n_bins >= 1.) {
+        return Err(BinsBuildError::Strategy);
+    }
+    // `n_bins` is a positive integer, small enough for the edges to fit in memory
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let n_bins = n_bins as usize;
+    #[allow(clippy::cast_precision_loss)]
+    let step = range / n_bins as f64;
+    // As in `numpy.linspace`, the last edge is exactly `max`
+    let mut edges = vec![min.clone(), max.clone()];
+    for i in 1..n_bins {
+        #[allow(clippy::cast_precision_loss)]
+        let edge = i as f64 * step + first_edge;
+        edges.push(T::from_f64(edge).ok_or(BinsBuildError::Strategy)?);
+    }
+    VariableWidth::new(edges)
+}
+
+/// Converts an observation to `f64`.
+///
+/// **Panics** if the conversion fails.
+fn to_f64<T: ToPrimitive>(value: &T) -> f64 {
+    value
+        .to_f64()
+        .expect("Converting observations to `f64` must not fail.")
+}
+
+/// Converts all the observations to `f64`.
+///
+/// **Panics** if any of the conversions fails.
+fn to_f64_values<T: ToPrimitive>(a: &ArrayRef<T, Ix1>) -> Array1<f64> {
+    a.map(to_f64)
+}
+
 #[cfg(test)]
 mod equispaced_tests {
     use super::EquiSpaced;
@@ -618,6 +1334,156 @@ mod fd_tests {
     }
 }
 
+#[cfg(test)]
+mod scott_tests {
+    use super::{BinClosure, BinsBuildingStrategy, Scott};
+    use ndarray::array;
+    use noisy_float::types::n64;
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(Scott::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(Scott::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn bin_width_matches_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let bin_width = Scott::from_array(&a).unwrap().bin_width();
+        assert_abs_diff_eq!(bin_width.raw(), 15.765_590_597_744_955, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn edges_match_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let strategy = Scott::from_array(&a).unwrap();
+        assert_eq!(strategy.n_bins(), 3);
+        let bins = strategy.build();
+        assert_eq!(
+            bins.edges().as_array_view(),
+            array![1., 14., 27., 40.].mapv(n64)
+        );
+        assert_eq!(bins.closure(), BinClosure::LastBinClosed);
+    }
+
+    #[test]
+    fn bins_use_the_uniform_lookup() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let bins = Scott::from_array(&a).unwrap().build();
+        assert!(bins.is_uniform());
+        assert_eq!(bins.index_of(&n64(40.)), Some(2));
+    }
+}
+
+#[cfg(test)]
+mod doane_tests {
+    use super::{BinClosure, BinsBuildingStrategy, Doane};
+    use ndarray::array;
+    use noisy_float::types::n64;
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(Doane::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn two_elements_are_not_enough() {
+        assert!(Doane::from_array(&array![1, 2]).unwrap_err().is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(Doane::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn bin_width_matches_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let bin_width = Doane::from_array(&a).unwrap().bin_width();
+        assert_abs_diff_eq!(bin_width.raw(), 5.708_147_487_476_602, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn edges_match_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let strategy = Doane::from_array(&a).unwrap();
+        assert_eq!(strategy.n_bins(), 7);
+        let bins = strategy.build();
+        let numpy_edges = array![
+            1.,
+            6.571_428_571_428_571,
+            12.142_857_142_857_142,
+            17.714_285_714_285_715,
+            23.285_714_285_714_285,
+            28.857_142_857_142_854,
+            34.428_571_428_571_43,
+            40.
+        ];
+        assert_eq!(bins.edges().as_array_view(), numpy_edges.mapv(n64));
+        assert_eq!(bins.closure(), BinClosure::LastBinClosed);
+        assert!(bins.is_uniform());
+    }
+}
+
+#[cfg(test)]
+mod stone_tests {
+    use super::{BinClosure, BinsBuildingStrategy, Stone};
+    use ndarray::array;
+    use noisy_float::types::n64;
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(Stone::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn single_element_is_not_enough() {
+        assert!(Stone::from_array(&array![1]).unwrap_err().is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(Stone::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn bin_width_matches_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let bin_width = Stone::from_array(&a).unwrap().bin_width();
+        assert_eq!(bin_width, n64(9.75));
+    }
+
+    #[test]
+    fn edges_match_numpy() {
+        let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 20., 40.].mapv(n64);
+        let strategy = Stone::from_array(&a).unwrap();
+        assert_eq!(strategy.n_bins(), 4);
+        let bins = strategy.build();
+        assert_eq!(
+            bins.edges().as_array_view(),
+            array![1., 10.75, 20.5, 30.25, 40.].mapv(n64)
+        );
+        assert_eq!(bins.closure(), BinClosure::LastBinClosed);
+        assert!(bins.is_uniform());
+    }
+}
+
 #[cfg(test)]
 mod auto_tests {
     use super::{Auto, BinsBuildingStrategy};
@@ -642,3 +1508,141 @@ mod auto_tests {
             .is_empty_input());
     }
 }
+
+#[cfg(test)]
+mod log_spaced_tests {
+    use super::{BinsBuildingStrategy, LogSpaced};
+    use crate::histogram::{Grid, HistogramExt};
+    use ndarray::{array, Array1, Axis};
+    use noisy_float::types::{n64, N64};
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(LogSpaced::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn non_positive_values_are_bad() {
+        assert!(LogSpaced::from_array(&array![0, 1, 10, 100])
+            .unwrap_err()
+            .is_strategy());
+        assert!(LogSpaced::from_array(&array![-1, 1, 10, 100])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(LogSpaced::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn zero_bins_are_bad() {
+        assert!(LogSpaced::with_n_bins(&array![1, 10], 0)
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn edges_have_a_constant_ratio() {
+        let a = array![1., 3., 20., 1000., 10.].mapv(n64);
+        let strategy = LogSpaced::with_n_bins(&a, 3).unwrap();
+        let edges = Array1::from(strategy.edges().to_vec()).mapv(N64::raw);
+        assert_abs_diff_eq!(edges, array![1., 10., 100., 1000.], epsilon = 1e-9);
+        assert_eq!(strategy.n_bins(), 3);
+    }
+
+    #[test]
+    fn bins_include_all_observations() {
+        let a = array![1., 1.5, 2., 3., 5., 8., 13., 40., 200., 1500., 9000.].mapv(n64);
+        let strategy = LogSpaced::from_array(&a).unwrap();
+        let bins = strategy.build();
+        assert_eq!(bins.len(), strategy.n_bins());
+        let histogram = a
+            .view()
+            .insert_axis(Axis(1))
+            .histogram(Grid::from(vec![bins]));
+        assert_eq!(histogram.total_count(), a.len());
+    }
+}
+
+#[cfg(test)]
+mod equal_frequency_tests {
+    use super::{BinsBuildingStrategy, EqualFrequency};
+    use ndarray::array;
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(EqualFrequency::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(EqualFrequency::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn edges_are_quantiles() {
+        let a = array![9, 1, 8, 2, 7, 3, 6, 4, 5];
+        let strategy = EqualFrequency::from_array(&a).unwrap();
+        assert_eq!(strategy.edges(), &[1, 3, 5, 7, 9]);
+        let bins = strategy.build();
+        let counts: Vec<_> = (0..bins.len())
+            .map(|i| a.iter().filter(|&v| bins.index_of(v) == Some(i)).count())
+            .collect();
+        assert_eq!(counts, vec![2, 2, 2, 3]);
+    }
+
+    #[test]
+    fn repeated_quantiles_are_merged() {
+        let a = array![1, 1, 1, 1, 1, 1, 2, 3];
+        let strategy = EqualFrequency::with_n_bins(&a, 4).unwrap();
+        assert_eq!(strategy.edges(), &[1, 3]);
+        assert_eq!(strategy.n_bins(), 1);
+    }
+}
+
+#[cfg(test)]
+mod bayesian_blocks_tests {
+    use super::{BayesianBlocks, BinsBuildingStrategy};
+    use ndarray::array;
+    use noisy_float::types::n64;
+
+    #[test]
+    fn constant_array_are_bad() {
+        assert!(BayesianBlocks::from_array(&array![1, 1, 1, 1, 1, 1, 1])
+            .unwrap_err()
+            .is_strategy());
+    }
+
+    #[test]
+    fn empty_arrays_are_bad() {
+        assert!(BayesianBlocks::<usize>::from_array(&array![])
+            .unwrap_err()
+            .is_empty_input());
+    }
+
+    #[test]
+    fn change_points_match_astropy() {
+        // astropy places the inner edges at 1.95, then at 2.5 and 60.5: the midpoints before the
+        // observations 2., then 3 and 61
+        let a = array![1., 1.1, 1.2, 1.3, 1.4, 1.5, 1.6, 1.7, 1.8, 1.9, 2., 5., 9., 13., 17., 20.]
+            .mapv(n64);
+        let strategy = BayesianBlocks::from_array(&a).unwrap();
+        assert_eq!(strategy.edges(), &[n64(1.), n64(2.), n64(20.)]);
+
+        let a = array![0, 0, 1, 1, 1, 2, 2, 3, 10, 20, 30, 40, 50, 60, 61, 61, 62, 62, 63];
+        let strategy = BayesianBlocks::from_array(&a).unwrap();
+        assert_eq!(strategy.edges(), &[0, 3, 61, 63]);
+        assert_eq!(strategy.n_bins(), 3);
+        assert_eq!(strategy.build().index_of(&63), Some(2));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::errors::{EmptyInput, ShapeMismatch};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::error;
+use std::fmt;
+
+/// Error building a [`KernelDensity`] estimator.
+///
+/// [`KernelDensity`]: ../struct.KernelDensity.html
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KdeError {
+    /// There were not enough observations to select the bandwidth (at least one observation
+    /// is needed for an explicit bandwidth, at least two otherwise).
+    EmptyInput,
+    /// The number of bandwidths does not match the number of dimensions of the observations.
+    ShapeMismatch(ShapeMismatch),
+    /// The bandwidth is not strictly positive and finite along some axis, e.g. because the
+    /// observations are constant along that axis.
+    InvalidBandwidth,
+}
+
+impl KdeError {
+    /// Returns whether `self` is the `EmptyInput` variant.
+    pub fn is_empty_input(&self) -> bool {
+        matches!(self, KdeError::EmptyInput)
+    }
+
+    /// Returns whether `self` is the `ShapeMismatch` variant.
+    pub fn is_shape_mismatch(&self) -> bool {
+        matches!(self, KdeError::ShapeMismatch(_))
+    }
+
+    /// Returns whether `self` is the `InvalidBandwidth` variant.
+    pub fn is_invalid_bandwidth(&self) -> bool {
+        matches!(self, KdeError::InvalidBandwidth)
+    }
+}
+
+impl fmt::Display for KdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KdeError::EmptyInput => write!(f, "Empty input."),
+            KdeError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+            KdeError::InvalidBandwidth => {
+                write!(f, "The bandwidth must be strictly positive and finite.")
+            }
+        }
+    }
+}
+
+impl error::Error for KdeError {}
+
+impl From<EmptyInput> for KdeError {
+    fn from(_: EmptyInput) -> Self {
+        KdeError::EmptyInput
+    }
+}
+
+impl From<ShapeMismatch> for KdeError {
+    fn from(err: ShapeMismatch) -> Self {
+        KdeError::ShapeMismatch(err)
+    }
+}
@@ -0,0 +1,162 @@
+//! Kernels used by [`KernelDensity`] to smooth observations.
+//!
+//! All kernels are symmetric probability density functions on the real line: multivariate
+//! densities are estimated using product kernels, i.e. the kernel is applied to each
+//! coordinate separately and the results are multiplied together.
+//!
+//! [`KernelDensity`]: ../struct.KernelDensity.html
+use num_traits::{Float, FromPrimitive};
+use std::f64::consts::PI;
+
+/// A kernel, i.e. a symmetric function integrating to 1 over the real line, used to estimate
+/// a density from observations.
+///
+/// See the [module-level documentation](index.html) for the kernels provided by the crate.
+pub trait Kernel {
+    /// Evaluates the kernel at `u`, the distance from an observation expressed in units of
+    /// bandwidth.
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive;
+}
+
+/// Converts an `f64` constant to `A`.
+fn constant<A: FromPrimitive>(value: f64) -> A {
+    A::from_f64(value).expect("Converting a kernel constant from `f64` must not fail.")
+}
+
+/// Returns `1 - u²` if `|u| <= 1`, `0` otherwise.
+fn one_minus_squared<A: Float>(u: A) -> A {
+    if u.abs() <= A::one() {
+        A::one() - u * u
+    } else {
+        A::zero()
+    }
+}
+
+/// Gaussian kernel, with unbounded support.
+///
+/// ```text
+/// K(u) = exp(-u²/2) / √(2π)
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Gaussian;
+
+/// Epanechnikov (parabolic) kernel, optimal in the sense of the mean integrated squared error.
+///
+/// ```text
+/// K(u) = 3/4 (1 - u²)    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Epanechnikov;
+
+/// Uniform (boxcar) kernel.
+///
+/// ```text
+/// K(u) = 1/2    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Uniform;
+
+/// Triangular kernel.
+///
+/// ```text
+/// K(u) = 1 - |u|    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Triangular;
+
+/// Biweight (quartic) kernel.
+///
+/// ```text
+/// K(u) = 15/16 (1 - u²)²    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Biweight;
+
+/// Triweight kernel.
+///
+/// ```text
+/// K(u) = 35/32 (1 - u²)³    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Triweight;
+
+/// Cosine kernel.
+///
+/// ```text
+/// K(u) = π/4 cos(πu/2)    for |u| <= 1
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cosine;
+
+impl Kernel for Gaussian {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        (-u * u / constant(2.)).exp() / constant::<A>(2. * PI).sqrt()
+    }
+}
+
+impl Kernel for Epanechnikov {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        constant::<A>(0.75) * one_minus_squared(u)
+    }
+}
+
+impl Kernel for Uniform {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        if u.abs() <= A::one() {
+            constant(0.5)
+        } else {
+            A::zero()
+        }
+    }
+}
+
+impl Kernel for Triangular {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        (A::one() - u.abs()).max(A::zero())
+    }
+}
+
+impl Kernel for Biweight {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        constant::<A>(15. / 16.) * one_minus_squared(u).powi(2)
+    }
+}
+
+impl Kernel for Triweight {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        constant::<A>(35. / 32.) * one_minus_squared(u).powi(3)
+    }
+}
+
+impl Kernel for Cosine {
+    fn evaluate<A>(&self, u: A) -> A
+    where
+        A: Float + FromPrimitive,
+    {
+        if u.abs() <= A::one() {
+            constant::<A>(PI / 4.) * (constant::<A>(PI / 2.) * u).cos()
+        } else {
+            A::zero()
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! Kernel density estimation.
+//!
+//! A [`KernelDensity`] estimator smooths each observation with a [kernel] scaled by a
+//! bandwidth, producing a continuous estimate of the probability density function the
+//! observations have been drawn from. It is built from a 2-dimensional array of
+//! observations (one observation per row, as for [`HistogramExt::histogram`]) or from a
+//! 1-dimensional array of scalar observations using [`KernelDensityExt`].
+//!
+//! Multivariate densities are estimated using product kernels with a bandwidth for each axis,
+//! selected according to a [`Bandwidth`] rule:
+//!
+//! ```text
+//!         1      n   d        x_j - X_ij
+//! f(x) = ───     Σ   Π  1/h_j K(──────────)
+//!         n     i=1 j=1            h_j
+//! ```
+//!
+//! [`KernelDensity`]: struct.KernelDensity.html
+//! [kernel]: kernels/index.html
+//! [`HistogramExt::histogram`]: ../trait.HistogramExt.html#tymethod.histogram
+//! [`KernelDensityExt`]: ../trait.KernelDensityExt.html
+//! [`Bandwidth`]: enum.Bandwidth.html
+use self::errors::KdeError;
+use self::kernels::Kernel;
+use crate::errors::{EmptyInput, ShapeMismatch};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use num_traits::{Float, FromPrimitive};
+
+pub mod errors;
+pub mod kernels;
+
+/// Rule used to select the bandwidths of a [`KernelDensity`] estimator, one for each axis
+/// of the observations.
+///
+/// Let `n` be the number of observations, `d` their number of dimensions and `σ_j` the
+/// sample standard deviation (with `ddof = 1`) of the observations along the `j`-th axis.
+///
+/// [`Scott`](#variant.Scott) and [`Silverman`](#variant.Silverman) are rules of thumb that
+/// are optimal for Gaussian data smoothed with a [`Gaussian`] kernel; they tend to oversmooth
+/// multimodal data. Their bandwidth factors (`n^(-1/(d+4))` and `(n(d+2)/4)^(-1/(d+4))`) match
+/// the ones used by SciPy's `gaussian_kde`, but the bandwidths are not the same in more than
+/// one dimension: SciPy scales the whole covariance matrix of the observations, while we scale
+/// each `σ_j` on its own (a diagonal bandwidth matrix).
+///
+/// [`KernelDensity`]: struct.KernelDensity.html
+/// [`Gaussian`]: kernels/struct.Gaussian.html
+#[derive(Clone, Debug, PartialEq)]
+pub enum Bandwidth<A> {
+    /// Scott's rule of thumb:
+    ///
+    /// ```text
+    /// h_j = σ_j n^(-1/(d+4))
+    /// ```
+    Scott,
+    /// Silverman's rule of thumb:
+    ///
+    /// ```text
+    /// h_j = σ_j (n (d+2) / 4)^(-1/(d+4))
+    /// ```
+    Silverman,
+    /// The same bandwidth along every axis.
+    Constant(A),
+    /// An explicit bandwidth for each axis.
+    PerAxis(Array1<A>),
+    /// Likelihood cross-validation: `h_j = c σ_j`, where the factor `c` is the one, among the
+    /// provided candidates, that maximizes the leave-one-out log-likelihood of the
+    /// observations
+    ///
+    /// ```text
+    ///  n
+    ///  Σ ln f_{-i}(X_i)
+    /// i=1
+    /// ```
+    ///
+    /// where `f_{-i}` is the density estimated from all the observations but the `i`-th one.
+    ///
+    /// The cost of the selection is `O(n² d)` for each candidate.
+    LikelihoodCrossValidation(Vec<A>),
+}
+
+/// Kernel density estimator.
+///
+/// See the [module-level documentation](index.html) for the definition of the estimated
+/// density.
+#[derive(Clone, Debug)]
+pub struct KernelDensity<A, K> {
+    observations: Array2<A>,
+    bandwidth: Array1<A>,
+    kernel: K,
+}
+
+impl<A, K> KernelDensity<A, K>
+where
+    A: Float + FromPrimitive,
+    K: Kernel,
+{
+    /// Returns a kernel density estimator for `observations`, a 2-dimensional array where
+    /// each row is an observation, with bandwidths selected according to `bandwidth`.
+    ///
+    /// Returns `Err(KdeError::EmptyInput)` if there are no observations, or if there is a
+    /// single observation and the bandwidth must be inferred from the data (i.e. it is not
+    /// [`Constant`](enum.Bandwidth.html#variant.Constant) or
+    /// [`PerAxis`](enum.Bandwidth.html#variant.PerAxis)).
+    ///
+    /// Returns `Err(KdeError::ShapeMismatch)` if a `PerAxis` bandwidth does not provide
+    /// exactly one bandwidth for each column of `observations`.
+    ///
+    /// Returns `Err(KdeError::InvalidBandwidth)` if any of the bandwidths is not strictly
+    /// positive and finite (e.g. if the observations are constant along an axis), or if
+    /// none of the cross-validation candidates yields a finite log-likelihood.
+    pub fn new(
+        observations: Array2<A>,
+        kernel: K,
+        bandwidth: Bandwidth<A>,
+    ) -> Result<Self, KdeError> {
+        let bandwidth = select_bandwidth(&observations, &kernel, bandwidth)?;
+        Ok(KernelDensity {
+            observations,
+            bandwidth,
+            kernel,
+        })
+    }
+
+    /// Returns the number of dimensions of the observations.
+    pub fn ndim(&self) -> usize {
+        self.observations.ncols()
+    }
+
+    /// Returns the number of observations.
+    pub fn n_observations(&self) -> usize {
+        self.observations.nrows()
+    }
+
+    /// Borrows a view on the observations, one for each row.
+    pub fn observations(&self) -> ArrayView2<'_, A> {
+        self.observations.view()
+    }
+
+    /// Borrows a view on the bandwidths selected for each axis.
+    pub fn bandwidth(&self) -> ArrayView1<'_, A> {
+        self.bandwidth.view()
+    }
+
+    /// Borrows the kernel used by the estimator.
+    pub fn kernel(&self) -> &K {
+        &self.kernel
+    }
+
+    /// Returns the estimated density at `point`.
+    ///
+    /// **Panics** if dimensions do not match: `self.ndim() != point.len()`.
+    ///
+    /// # Example:
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{kde::{kernels::Epanechnikov, Bandwidth}, KernelDensityExt};
+    ///
+    /// let observations = array![[0.], [1.]];
+    /// let kde = observations.kernel_density(Epanechnikov, Bandwidth::Constant(2.))?;
+    ///
+    /// // (K(0) + K(-1/2)) / (2 × 2), with K(u) = 3/4 (1 - u²)
+    /// assert_abs_diff_eq!(kde.evaluate(&array![0.]), (0.75 + 0.5625) / 4.);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn evaluate(&self, point: &ArrayRef<A, Ix1>) -> A {
+        assert_eq!(
+            point.len(),
+            self.ndim(),
+            "The point must have the same number of dimensions as the observations."
+        );
+        kernel_sum(&self.observations, &self.kernel, &self.bandwidth, point)
+            / (A::from_usize(self.n_observations()).unwrap() * self.bandwidth.product())
+    }
+
+    /// Returns the estimated density at each row of `points`.
+    ///
+    /// **Panics** if dimensions do not match: `self.ndim() != points.ncols()`.
+    pub fn evaluate_points(&self, points: &ArrayRef<A, Ix2>) -> Array1<A> {
+        assert_eq!(
+            points.ncols(),
+            self.ndim(),
+            "The points must have the same number of dimensions as the observations."
+        );
+        points
+            .rows()
+            .into_iter()
+            .map(|point| self.evaluate(&point))
+            .collect()
+    }
+}
+
+/// Returns the sum, over all the observations, of the product kernel evaluated at `point`
+/// (not normalized by the number of observations nor by the bandwidths).
+fn kernel_sum<A, K>(
+    observations: &ArrayRef<A, Ix2>,
+    kernel: &K,
+    bandwidth: &ArrayRef<A, Ix1>,
+    point: &ArrayRef<A, Ix1>,
+) -> A
+where
+    A: Float + FromPrimitive,
+    K: Kernel,
+{
+    observations
+        .rows()
+        .into_iter()
+        .fold(A::zero(), |sum, observation| {
+            sum + Zip::from(&observation)
+                .and(point)
+                .and(bandwidth)
+                .fold(A::one(), |product, &x, &p, &h| {
+                    product * kernel.evaluate((p - x) / h)
+                })
+        })
+}
+
+/// Returns the leave-one-out log-likelihood of `observations` for the given bandwidths.
+fn leave_one_out_log_likelihood<A, K>(
+    observations: &ArrayRef<A, Ix2>,
+    kernel: &K,
+    bandwidth: &ArrayRef<A, Ix1>,
+) -> A
+where
+    A: Float + FromPrimitive,
+    K: Kernel,
+{
+    let n = observations.nrows();
+    let normalization = A::from_usize(n - 1).unwrap() * bandwidth.product();
+    let self_contribution = bandwidth
+        .iter()
+        .fold(A::one(), |product, _| product * kernel.evaluate(A::zero()));
+    observations
+        .rows()
+        .into_iter()
+        .fold(A::zero(), |log_likelihood, observation| {
+            let sum = kernel_sum(observations, kernel, bandwidth, &observation) - self_contribution;
+            log_likelihood + (sum.max(A::zero()) / normalization).ln()
+        })
+}
+
+fn select_bandwidth<A, K>(
+    observations: &ArrayRef<A, Ix2>,
+    kernel: &K,
+    bandwidth: Bandwidth<A>,
+) -> Result<Array1<A>, KdeError>
+where
+    A: Float + FromPrimitive,
+    K: Kernel,
+{
+    let (n, d) = observations.dim();
+    let bandwidth = match bandwidth {
+        Bandwidth::Constant(h) => {
+            if n == 0 {
+                return Err(EmptyInput.into());
+            }
+            Array1::from_elem(d, h)
+        }
+        Bandwidth::PerAxis(h) => {
+            if n == 0 {
+                return Err(EmptyInput.into());
+            }
+            if h.len() != d {
+                return Err(ShapeMismatch {
+                    first_shape: vec![d],
+                    second_shape: h.shape().to_vec(),
+                }
+                .into());
+            }
+            h
+        }
+        rule => {
+            if n < 2 {
+                return Err(EmptyInput.into());
+            }
+            let std = observations.var_axis(Axis(0), A::one()).mapv(A::sqrt);
+            let n = A::from_usize(n).unwrap();
+            let d = A::from_usize(d).unwrap();
+            let exponent = -A::one() / (d + A::from_usize(4).unwrap());
+            match rule {
+                Bandwidth::Scott => {
+                    let factor = n.powf(exponent);
+                    std.mapv(|sigma| sigma * factor)
+                }
+                Bandwidth::Silverman => {
+                    let two = A::from_usize(2).unwrap();
+                    let factor = (n * (d + two) / (two * two)).powf(exponent);
+                    std.mapv(|sigma| sigma * factor)
+                }
+                Bandwidth::LikelihoodCrossValidation(factors) => {
+                    let mut best: Option<(A, A)> = None;
+                    for factor in factors {
+                        let log_likelihood = leave_one_out_log_likelihood(
+                            observations,
+                            kernel,
+                            &std.mapv(|sigma| sigma * factor),
+                        );
+                        if log_likelihood.is_finite()
+                            && best.map_or(true, |(_, best)| log_likelihood > best)
+                        {
+                            best = Some((factor, log_likelihood));
+                        }
+                    }
+                    match best {
+                        Some((factor, _)) => std.mapv(|sigma| sigma * factor),
+                        None => return Err(KdeError::InvalidBandwidth),
+                    }
+                }
+                Bandwidth::Constant(_) | Bandwidth::PerAxis(_) => unreachable!(),
+            }
+        }
+    };
+    if bandwidth.iter().all(|&h| h > A::zero() && h.is_finite()) {
+        Ok(bandwidth)
+    } else {
+        Err(KdeError::InvalidBandwidth)
+    }
+}
+
+/// Extension trait for `ArrayRef` providing methods to build kernel density estimators.
+pub trait KernelDensityExt<A> {
+    /// Returns a [`KernelDensity`] estimator built from the observations in `self`, using
+    /// `kernel` and bandwidths selected according to `bandwidth`.
+    ///
+    /// For a 2-dimensional array, each row is an observation: `self.shape()[0]` is the number
+    /// of observations, `self.shape()[1]` their number of dimensions.
+    /// For a 1-dimensional array, each element is a scalar observation.
+    ///
+    /// The observations are copied into the estimator.
+    ///
+    /// See [`KernelDensity::new`] for the errors that can be returned.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{kde::{kernels::Gaussian, Bandwidth}, KernelDensityExt};
+    ///
+    /// let observations = array![-1.2, -0.3, 0.1, 0.2, 0.4, 1.5];
+    /// let kde = observations.kernel_density(Gaussian, Bandwidth::Silverman)?;
+    ///
+    /// assert_eq!(kde.ndim(), 1);
+    /// assert!(kde.evaluate(&array![0.]) > kde.evaluate(&array![2.]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`KernelDensity`]: kde/struct.KernelDensity.html
+    /// [`KernelDensity::new`]: kde/struct.KernelDensity.html#method.new
+    fn kernel_density<K>(
+        &self,
+        kernel: K,
+        bandwidth: Bandwidth<A>,
+    ) -> Result<KernelDensity<A, K>, KdeError>
+    where
+        A: Float + FromPrimitive,
+        K: Kernel;
+
+    private_decl! {}
+}
+
+impl<A> KernelDensityExt<A> for ArrayRef<A, Ix2>
+where
+    A: Clone,
+{
+    fn kernel_density<K>(
+        &self,
+        kernel: K,
+        bandwidth: Bandwidth<A>,
+    ) -> Result<KernelDensity<A, K>, KdeError>
+    where
+        A: Float + FromPrimitive,
+        K: Kernel,
+    {
+        KernelDensity::new(self.to_owned(), kernel, bandwidth)
+    }
+
+    private_impl! {}
+}
+
+impl<A> KernelDensityExt<A> for ArrayRef<A, Ix1>
+where
+    A: Clone,
+{
+    fn kernel_density<K>(
+        &self,
+        kernel: K,
+        bandwidth: Bandwidth<A>,
+    ) -> Result<KernelDensity<A, K>, KdeError>
+    where
+        A: Float + FromPrimitive,
+        K: Kernel,
+    {
+        KernelDensity::new(self.to_owned().insert_axis(Axis(1)), kernel, bandwidth)
+    }
+
+    private_impl! {}
+}
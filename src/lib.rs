@@ -4,11 +4,16 @@
 //! Currently available routines include:
 //! - [order statistics] (minimum, maximum, median, quantiles, etc.);
 //! - [summary statistics] (mean, skewness, kurtosis, central moments, etc.)
+//!   and [streaming moments] over chunked or partitioned data;
+//! - [robust statistics] (median absolute deviation, interquartile range, Qn, Sn,
+//!   trimmed and winsorized means, etc.);
 //! - [partitioning];
-//! - [correlation analysis] (covariance, pearson correlation);
+//! - [correlation analysis] (covariance, shrinkage estimators, pearson, spearman and
+//!   kendall correlation);
 //! - [measures from information theory] (entropy, KL divergence, etc.);
 //! - [measures of deviation] (count equal, L1, L2 distances, mean squared err etc.)
-//! - [histogram computation].
+//! - [histogram computation];
+//! - [kernel density estimation].
 //!
 //! Please feel free to contribute new functionality! A roadmap can be found [here].
 //!
@@ -16,27 +21,47 @@
 //! [`NumPy`] (Python) and [`StatsBase.jl`] (Julia) - any contribution bringing us closer to
 //! feature parity is more than welcome!
 //!
+//! # Crate features
+//!
+//! - `rayon`: adds parallel versions (prefixed with `par_`) of the most expensive reductions
+//!   and axis-wise methods, running on the [`rayon`] thread pool.
+//!   Floating point reductions combine partial results in a different order than their
+//!   sequential counterparts, hence they are not bit-for-bit identical: on well-conditioned
+//!   inputs the relative difference is of the order of `log2(n) * ε`, where `n` is the number
+//!   of elements and `ε` is the machine epsilon of the element type.
+//!   Integer and order-based results (counts, histograms, quantiles) are identical.
+//! - `serde`: implements [`serde`]'s `Serialize` and `Deserialize` for the [histogram] data
+//!   structures and for the error types.
+//!
 //! [`ndarray-stats`]: https://github.com/rust-ndarray/ndarray-stats/
 //! [`ndarray`]: https://github.com/rust-ndarray/ndarray
 //! [order statistics]: trait.QuantileExt.html
 //! [partitioning]: trait.Sort1dExt.html
 //! [summary statistics]: trait.SummaryStatisticsExt.html
+//! [streaming moments]: struct.MomentsAccumulator.html
+//! [robust statistics]: robust/trait.RobustStatisticsExt.html
 //! [correlation analysis]: trait.CorrelationExt.html
 //! [measures of deviation]: trait.DeviationExt.html
 //! [measures from information theory]: trait.EntropyExt.html
 //! [histogram computation]: histogram/index.html
+//! [kernel density estimation]: kde/index.html
 //! [here]: https://github.com/rust-ndarray/ndarray-stats/issues/1
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy-1.14.1/reference/routines.statistics.html
 //! [`StatsBase.jl`]: https://juliastats.github.io/StatsBase.jl/latest/
+//! [`rayon`]: https://docs.rs/rayon
+//! [`serde`]: https://docs.rs/serde
+//! [histogram]: histogram/index.html
 
 pub use crate::correlation::CorrelationExt;
 pub use crate::deviation::DeviationExt;
 pub use crate::entropy::EntropyExt;
-pub use crate::histogram::HistogramExt;
+pub use crate::histogram::{HistogramExt, HistogramSkipNanExt};
+pub use crate::kde::KernelDensityExt;
 pub use crate::maybe_nan::{MaybeNan, MaybeNanExt};
 pub use crate::quantile::{interpolate, Quantile1dExt, QuantileExt};
+pub use crate::robust::RobustStatisticsExt;
 pub use crate::sort::Sort1dExt;
-pub use crate::summary_statistics::SummaryStatisticsExt;
+pub use crate::summary_statistics::{MomentsAccumulator, SummaryStatisticsExt};
 
 #[cfg(test)]
 #[macro_use]
@@ -103,7 +128,9 @@ mod deviation;
 mod entropy;
 pub mod errors;
 pub mod histogram;
+pub mod kde;
 mod maybe_nan;
 mod quantile;
+pub mod robust;
 mod sort;
 mod summary_statistics;
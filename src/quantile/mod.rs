@@ -4,7 +4,7 @@ use crate::errors::QuantileError;
 use crate::errors::{EmptyInput, MinMaxError, MinMaxError::UndefinedOrder};
 use crate::{MaybeNan, MaybeNanExt};
 use ndarray::prelude::*;
-use ndarray::{RemoveAxis, Zip};
+use ndarray::{iter::LanesMut, RemoveAxis, Zip};
 use noisy_float::types::N64;
 use std::cmp;
 
@@ -273,6 +273,41 @@ where
         A::NotNan: Clone + Ord,
         I: Interpolate<A::NotNan>;
 
+    /// Parallel version of [`quantiles_axis_mut`](#tymethod.quantiles_axis_mut).
+    ///
+    /// The quantiles of different lanes along `axis` are computed in parallel on the `rayon`
+    /// thread pool. Each lane is processed exactly as in the sequential version, hence
+    /// the result is identical.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_quantiles_axis_mut<I>(
+        &mut self,
+        axis: Axis,
+        qs: &ArrayRef<N64, Ix1>,
+        interpolate: &I,
+    ) -> Result<Array<A, D>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Send + Sync,
+        I: Interpolate<A>;
+
+    /// Parallel version of [`quantile_axis_mut`](#tymethod.quantile_axis_mut), see
+    /// [`par_quantiles_axis_mut`](#tymethod.par_quantiles_axis_mut) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_quantile_axis_mut<I>(
+        &mut self,
+        axis: Axis,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Send + Sync,
+        I: Interpolate<A>;
+
     private_decl! {}
 }
 
@@ -425,71 +460,9 @@ where
         A: Ord + Clone,
         I: Interpolate<A>,
     {
-        // Minimize number of type parameters to avoid monomorphization bloat.
-        fn quantiles_axis_mut<A, D, I>(
-            mut data: ArrayViewMut<'_, A, D>,
-            axis: Axis,
-            qs: ArrayView1<'_, N64>,
-            _interpolate: &I,
-        ) -> Result<Array<A, D>, QuantileError>
-        where
-            D: RemoveAxis,
-            A: Ord + Clone,
-            I: Interpolate<A>,
-        {
-            for &q in qs {
-                if !((q >= 0.) && (q <= 1.)) {
-                    return Err(QuantileError::InvalidQuantile(q));
-                }
-            }
-
-            let axis_len = data.len_of(axis);
-            if axis_len == 0 {
-                return Err(QuantileError::EmptyInput);
-            }
-
-            let mut results_shape = data.raw_dim();
-            results_shape[axis.index()] = qs.len();
-            if results_shape.size() == 0 {
-                return Ok(Array::from_shape_vec(results_shape, Vec::new()).unwrap());
-            }
-
-            let mut searched_indexes = Vec::with_capacity(2 * qs.len());
-            for &q in &qs {
-                if I::needs_lower(q, axis_len) {
-                    searched_indexes.push(lower_index(q, axis_len));
-                }
-                if I::needs_higher(q, axis_len) {
-                    searched_indexes.push(higher_index(q, axis_len));
-                }
-            }
-            searched_indexes.sort();
-            searched_indexes.dedup();
-
-            let mut results = Array::from_elem(results_shape, data.first().unwrap().clone());
-            Zip::from(results.lanes_mut(axis))
-                .and(data.lanes_mut(axis))
-                .for_each(|mut results, mut data| {
-                    let index_map =
-                        get_many_from_sorted_mut_unchecked(&mut data, &searched_indexes);
-                    for (result, &q) in results.iter_mut().zip(qs) {
-                        let lower = if I::needs_lower(q, axis_len) {
-                            Some(index_map[&lower_index(q, axis_len)].clone())
-                        } else {
-                            None
-                        };
-                        let higher = if I::needs_higher(q, axis_len) {
-                            Some(index_map[&higher_index(q, axis_len)].clone())
-                        } else {
-                            None
-                        };
-                        *result = I::interpolate(lower, higher, q, axis_len);
-                    }
-                });
-            Ok(results)
-        }
-
-        quantiles_axis_mut(self.view_mut(), axis, qs.view(), interpolate)
+        quantiles_axis_mut(self.view_mut(), axis, qs.view(), interpolate, |lanes, f| {
+            lanes.for_each(f)
+        })
     }
 
     fn quantile_axis_mut<I>(
@@ -543,9 +516,116 @@ where
         Ok(quantile)
     }
 
+    #[cfg(feature = "rayon")]
+    fn par_quantiles_axis_mut<I>(
+        &mut self,
+        axis: Axis,
+        qs: &ArrayRef<N64, Ix1>,
+        interpolate: &I,
+    ) -> Result<Array<A, D>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Send + Sync,
+        I: Interpolate<A>,
+    {
+        quantiles_axis_mut(self.view_mut(), axis, qs.view(), interpolate, |lanes, f| {
+            lanes.par_for_each(f)
+        })
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_quantile_axis_mut<I>(
+        &mut self,
+        axis: Axis,
+        q: N64,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, QuantileError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Send + Sync,
+        I: Interpolate<A>,
+    {
+        self.par_quantiles_axis_mut(axis, &aview1(&[q]), interpolate)
+            .map(|a| a.index_axis_move(axis, 0))
+    }
+
     private_impl! {}
 }
 
+/// Shared implementation of `quantiles_axis_mut` and `par_quantiles_axis_mut`.
+///
+/// `for_each_lane` drives the computation of the quantiles of each lane along `axis`,
+/// either sequentially or in parallel. We use a trait object for the per-lane kernel
+/// to minimize the number of type parameters and avoid monomorphization bloat.
+fn quantiles_axis_mut<A, D, I, F>(
+    mut data: ArrayViewMut<'_, A, D>,
+    axis: Axis,
+    qs: ArrayView1<'_, N64>,
+    _interpolate: &I,
+    for_each_lane: F,
+) -> Result<Array<A, D>, QuantileError>
+where
+    D: RemoveAxis,
+    A: Ord + Clone,
+    I: Interpolate<A>,
+    F: for<'a> FnOnce(
+        Zip<(LanesMut<'a, A, D::Smaller>, LanesMut<'a, A, D::Smaller>), D::Smaller>,
+        &'a (dyn Fn(ArrayViewMut1<'a, A>, ArrayViewMut1<'a, A>) + Sync),
+    ),
+{
+    for &q in qs {
+        if !((q >= 0.) && (q <= 1.)) {
+            return Err(QuantileError::InvalidQuantile(q));
+        }
+    }
+
+    let axis_len = data.len_of(axis);
+    if axis_len == 0 {
+        return Err(QuantileError::EmptyInput);
+    }
+
+    let mut results_shape = data.raw_dim();
+    results_shape[axis.index()] = qs.len();
+    if results_shape.size() == 0 {
+        return Ok(Array::from_shape_vec(results_shape, Vec::new()).unwrap());
+    }
+
+    let mut searched_indexes = Vec::with_capacity(2 * qs.len());
+    for &q in &qs {
+        if I::needs_lower(q, axis_len) {
+            searched_indexes.push(lower_index(q, axis_len));
+        }
+        if I::needs_higher(q, axis_len) {
+            searched_indexes.push(higher_index(q, axis_len));
+        }
+    }
+    searched_indexes.sort();
+    searched_indexes.dedup();
+
+    let mut results = Array::from_elem(results_shape, data.first().unwrap().clone());
+    let quantiles_of_lane = |mut results: ArrayViewMut1<'_, A>, mut data: ArrayViewMut1<'_, A>| {
+        let index_map = get_many_from_sorted_mut_unchecked(&mut data, &searched_indexes);
+        for (result, &q) in results.iter_mut().zip(qs) {
+            let lower = if I::needs_lower(q, axis_len) {
+                Some(index_map[&lower_index(q, axis_len)].clone())
+            } else {
+                None
+            };
+            let higher = if I::needs_higher(q, axis_len) {
+                Some(index_map[&higher_index(q, axis_len)].clone())
+            } else {
+                None
+            };
+            *result = I::interpolate(lower, higher, q, axis_len);
+        }
+    };
+    for_each_lane(
+        Zip::from(results.lanes_mut(axis)).and(data.lanes_mut(axis)),
+        &quantiles_of_lane,
+    );
+    Ok(results)
+}
+
 /// Quantile methods for 1-D arrays.
 pub trait Quantile1dExt<A> {
     /// Return the qth quantile of the data.
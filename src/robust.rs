@@ -0,0 +1,1136 @@
+//! Robust statistics, i.e. estimators resistant to outliers.
+use crate::errors::EmptyInput;
+use crate::quantile::{interpolate::Interpolate, Quantile1dExt};
+use crate::summary_statistics::var_or_nan;
+use crate::{MaybeNan, Sort1dExt};
+use ndarray::{aview1, Array, Array1, ArrayRef, ArrayView1, Axis, Dimension, RemoveAxis};
+use noisy_float::types::n64;
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Scaling of the robust scale estimators of [`RobustStatisticsExt`].
+///
+/// [`RobustStatisticsExt`]: trait.RobustStatisticsExt.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scaling {
+    /// The raw value of the estimator, e.g. the median of the absolute deviations.
+    Raw,
+    /// The value of the estimator multiplied by a constant factor, such that it is a
+    /// consistent estimator of the standard deviation for normally distributed data.
+    Normal,
+}
+
+impl Scaling {
+    /// Returns `value` multiplied by `normal_factor` for `Scaling::Normal`, `value` otherwise.
+    ///
+    /// **Panics** if the conversion of the scaled value from or to `f64` fails.
+    fn apply<A>(self, value: A, normal_factor: f64) -> A
+    where
+        A: ToPrimitive + FromPrimitive,
+    {
+        match self {
+            Scaling::Raw => value,
+            Scaling::Normal => {
+                let value = value
+                    .to_f64()
+                    .expect("Converting the estimate to `f64` must not fail.");
+                A::from_f64(value * normal_factor)
+                    .expect("Converting the scaled estimate from `f64` must not fail.")
+            }
+        }
+    }
+}
+
+/// 1 / Φ⁻¹(3/4), where Φ is the cumulative distribution function of the standard normal
+/// distribution.
+const MAD_NORMAL_FACTOR: f64 = 1.482_602_218_505_602;
+/// 1 / (2 Φ⁻¹(3/4)).
+const IQR_NORMAL_FACTOR: f64 = 1. / 1.348_979_500_392_163_4;
+/// 1 / (√2 Φ⁻¹(5/8)).
+const QN_NORMAL_FACTOR: f64 = 2.219_144_465_985_076;
+/// The asymptotic consistency factor given by Rousseeuw and Croux (1993).
+const SN_NORMAL_FACTOR: f64 = 1.1926;
+
+/// Extension trait for `ArrayRef` providing robust statistics, i.e. estimators that are not
+/// unduly affected by outliers.
+///
+/// The estimators are computed over all the elements of the array, or independently
+/// along each 1-dimensional lane of an axis with the `_axis` methods. The `_skipnan` methods
+/// ignore NaN elements; they return NaN if all the elements (of a lane) are NaN.
+///
+/// All the estimators rely on order statistics: the elements need to be totally ordered (e.g.
+/// [`N64`] instead of `f64`, or `f64` for the `_skipnan` methods).
+///
+/// [`N64`]: https://docs.rs/noisy_float/*/noisy_float/types/type.N64.html
+pub trait RobustStatisticsExt<A, D>
+where
+    D: Dimension,
+{
+    /// Returns the [median absolute deviation] (MAD) of all the elements in the array:
+    ///
+    /// ```text
+    /// MAD = median(|xᵢ - median(x)|)
+    /// ```
+    ///
+    /// Both medians are computed with [`quantile_mut`], using the `interpolate` strategy.
+    /// With `Scaling::Normal`, the MAD is multiplied by 1 / Φ⁻¹(3/4) ≈ 1.4826.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the MAD from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Midpoint, robust::Scaling, RobustStatisticsExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![1., 1., 2., 2., 4., 6., 9.].mapv(n64);
+    /// assert_eq!(a.mad(Scaling::Raw, &Midpoint)?, n64(1.));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`quantile_mut`]: ../trait.Quantile1dExt.html#tymethod.quantile_mut
+    fn mad<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>;
+
+    /// Returns the [median absolute deviation] of each 1-dimensional lane along `axis`, see
+    /// [`mad`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any MAD from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`mad`]: #tymethod.mad
+    fn mad_axis<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>;
+
+    /// Returns the [median absolute deviation] of all the elements in the array, skipping NaN
+    /// values, see [`mad`].
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the MAD from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`mad`]: #tymethod.mad
+    fn mad_skipnan<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the [median absolute deviation] of each 1-dimensional lane along `axis`,
+    /// skipping NaN values, see [`mad`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any MAD from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [median absolute deviation]: https://en.wikipedia.org/wiki/Median_absolute_deviation
+    /// [`mad`]: #tymethod.mad
+    fn mad_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the [interquartile range] (IQR) of all the elements in the array, i.e. the
+    /// difference between their upper and lower quartiles, computed with [`quantile_mut`]
+    /// using the `interpolate` strategy.
+    ///
+    /// With `Scaling::Normal`, the IQR is divided by 2 Φ⁻¹(3/4) ≈ 1.349.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the IQR from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Linear, robust::Scaling, RobustStatisticsExt};
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![1., 2., 3., 4., 5., 6., 7., 8., 9.].mapv(n64);
+    /// assert_eq!(a.iqr(Scaling::Raw, &Linear)?, n64(4.));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`quantile_mut`]: ../trait.Quantile1dExt.html#tymethod.quantile_mut
+    fn iqr<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>;
+
+    /// Returns the [interquartile range] of each 1-dimensional lane along `axis`, see [`iqr`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any IQR from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`iqr`]: #tymethod.iqr
+    fn iqr_axis<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>;
+
+    /// Returns the [interquartile range] of all the elements in the array, skipping NaN
+    /// values, see [`iqr`].
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the IQR from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`iqr`]: #tymethod.iqr
+    fn iqr_skipnan<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the [interquartile range] of each 1-dimensional lane along `axis`, skipping NaN
+    /// values, see [`iqr`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any IQR from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [interquartile range]: https://en.wikipedia.org/wiki/Interquartile_range
+    /// [`iqr`]: #tymethod.iqr
+    fn iqr_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>;
+
+    /// Returns the Qn estimator of scale of [Rousseeuw and Croux (1993)][paper] of all the
+    /// elements in the array, i.e. the `k`-th smallest of the pairwise distances between
+    /// elements:
+    ///
+    /// ```text
+    /// Qn = {|xᵢ - xⱼ|; i < j}₍ₖ₎    with k = h (h - 1) / 2 and h = ⌊n / 2⌋ + 1
+    /// ```
+    ///
+    /// where `n` is the number of elements. The Qn of a single element is 0. With
+    /// `Scaling::Normal`, the Qn is multiplied by the asymptotic consistency factor
+    /// 1 / (√2 Φ⁻¹(5/8)) ≈ 2.2191, without small-sample correction.
+    ///
+    /// Unlike the MAD, the Qn does not assume a symmetric distribution and has a 82%
+    /// efficiency for normally distributed data. The `k`-th smallest distance is selected with
+    /// the algorithm of Croux and Rousseeuw (1992), without computing all the pairwise
+    /// distances: this takes `O(n log n)` time and `O(n)` memory.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the Qn from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{robust::Scaling, RobustStatisticsExt};
+    ///
+    /// // The 3 smallest distances are 1, 1 and 2
+    /// let a = array![1, 2, 3, 5, 100];
+    /// assert_eq!(a.qn(Scaling::Raw)?, 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [paper]: https://doi.org/10.1080/01621459.1993.10476408
+    fn qn(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + Zero + ToPrimitive + FromPrimitive;
+
+    /// Returns the Qn estimator of scale of each 1-dimensional lane along `axis`, see [`qn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any Qn from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [`qn`]: #tymethod.qn
+    fn qn_axis(&self, axis: Axis, scaling: Scaling) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + Zero + ToPrimitive + FromPrimitive;
+
+    /// Returns the Qn estimator of scale of all the elements in the array, skipping NaN
+    /// values, see [`qn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the Qn from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// [`qn`]: #tymethod.qn
+    fn qn_skipnan(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Zero + ToPrimitive + FromPrimitive;
+
+    /// Returns the Qn estimator of scale of each 1-dimensional lane along `axis`, skipping NaN
+    /// values, see [`qn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any Qn from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [`qn`]: #tymethod.qn
+    fn qn_axis_skipnan(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Zero + ToPrimitive + FromPrimitive;
+
+    /// Returns the Sn estimator of scale of [Rousseeuw and Croux (1993)][paper] of all the
+    /// elements in the array:
+    ///
+    /// ```text
+    /// Sn = lomedᵢ himedⱼ |xᵢ - xⱼ|
+    /// ```
+    ///
+    /// where, for `n` elements, the high median `himed` is the (⌊n / 2⌋ + 1)-th smallest value
+    /// and the low median `lomed` is the ⌊(n + 1) / 2⌋-th smallest value. With
+    /// `Scaling::Normal`, the Sn is multiplied by the asymptotic consistency factor 1.1926,
+    /// without small-sample correction.
+    ///
+    /// Unlike the MAD, the Sn does not assume a symmetric distribution and has a 58%
+    /// efficiency for normally distributed data. The inner high medians are computed with
+    /// quickselect: this takes `O(n²)` time on average and `O(n)` memory.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the Sn from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{robust::Scaling, RobustStatisticsExt};
+    ///
+    /// // The high medians of the distances to each element are 2, 1, 2, 3 and 97
+    /// let a = array![1, 2, 3, 5, 100];
+    /// assert_eq!(a.sn(Scaling::Raw)?, 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [paper]: https://doi.org/10.1080/01621459.1993.10476408
+    fn sn(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive;
+
+    /// Returns the Sn estimator of scale of each 1-dimensional lane along `axis`, see [`sn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any Sn from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [`sn`]: #tymethod.sn
+    fn sn_axis(&self, axis: Axis, scaling: Scaling) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive;
+
+    /// Returns the Sn estimator of scale of all the elements in the array, skipping NaN
+    /// values, see [`sn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if the conversion of the Sn from or to `f64` fails, with `Scaling::Normal`.
+    ///
+    /// [`sn`]: #tymethod.sn
+    fn sn_skipnan(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive;
+
+    /// Returns the Sn estimator of scale of each 1-dimensional lane along `axis`, skipping NaN
+    /// values, see [`sn`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the conversion of any Sn from or to `f64`
+    /// fails, with `Scaling::Normal`.
+    ///
+    /// [`sn`]: #tymethod.sn
+    fn sn_axis_skipnan(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive;
+
+    /// Returns the [trimmed mean] of all the elements in the array, i.e. the arithmetic mean of
+    /// the elements left after discarding the `g` smallest and the `g` largest ones, where
+    ///
+    /// ```text
+    /// g = ⌊proportion · n⌋
+    /// ```
+    ///
+    /// and `n` is the number of elements. The cut points are retrieved with
+    /// [`get_many_from_sorted_mut`], in `O(n)` time on average.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if `proportion` is not in `[0, 0.5)`, or if `A::from_usize()` fails for `g`
+    /// or `n - 2g`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::RobustStatisticsExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// let a = array![1., 2., 3., 7., 100.].mapv(n64);
+    /// assert_eq!(a.trimmed_mean(0.2)?, n64(4.));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [trimmed mean]: https://en.wikipedia.org/wiki/Truncated_mean
+    /// [`get_many_from_sorted_mut`]: ../trait.Sort1dExt.html#tymethod.get_many_from_sorted_mut
+    fn trimmed_mean(&self, proportion: f64) -> Result<A, EmptyInput>
+    where
+        A: Ord
+            + Clone
+            + FromPrimitive
+            + Add<Output = A>
+            + Sub<Output = A>
+            + Mul<Output = A>
+            + Div<Output = A>
+            + Zero;
+
+    /// Returns the [trimmed mean] of each 1-dimensional lane along `axis`, see
+    /// [`trimmed_mean`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `proportion` is not in `[0, 0.5)`, or if
+    /// `A::from_usize()` fails for `g` or `n - 2g`.
+    ///
+    /// [trimmed mean]: https://en.wikipedia.org/wiki/Truncated_mean
+    /// [`trimmed_mean`]: #tymethod.trimmed_mean
+    fn trimmed_mean_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord
+            + Clone
+            + FromPrimitive
+            + Add<Output = A>
+            + Sub<Output = A>
+            + Mul<Output = A>
+            + Div<Output = A>
+            + Zero;
+
+    /// Returns the [winsorized mean] of all the elements in the array, i.e. their arithmetic
+    /// mean after replacing the `g` smallest ones with the `(g + 1)`-th smallest one and the
+    /// `g` largest ones with the `(g + 1)`-th largest one, where
+    ///
+    /// ```text
+    /// g = ⌊proportion · n⌋
+    /// ```
+    ///
+    /// and `n` is the number of elements. The cut points are retrieved with
+    /// [`get_many_from_sorted_mut`], in `O(n)` time on average.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty.
+    ///
+    /// **Panics** if `proportion` is not in `[0, 0.5)`, or if `A::from_usize()` fails for `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::RobustStatisticsExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// // Winsorized to [2., 2., 3., 7., 7.]
+    /// let a = array![1., 2., 3., 7., 100.].mapv(n64);
+    /// assert_eq!(a.winsorized_mean(0.2)?, n64(4.2));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [winsorized mean]: https://en.wikipedia.org/wiki/Winsorized_mean
+    /// [`get_many_from_sorted_mut`]: ../trait.Sort1dExt.html#tymethod.get_many_from_sorted_mut
+    fn winsorized_mean(&self, proportion: f64) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero;
+
+    /// Returns the [winsorized mean] of each 1-dimensional lane along `axis`, see
+    /// [`winsorized_mean`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `proportion` is not in `[0, 0.5)`, or if
+    /// `A::from_usize()` fails for `n`.
+    ///
+    /// [winsorized mean]: https://en.wikipedia.org/wiki/Winsorized_mean
+    /// [`winsorized_mean`]: #tymethod.winsorized_mean
+    fn winsorized_mean_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero;
+
+    /// Returns the winsorized variance of all the elements in the array, i.e. the variance of
+    /// the elements winsorized as in [`winsorized_mean`]:
+    ///
+    /// ```text
+    ///       1       n
+    /// ―――――――――――   ∑ (wᵢ - w̅)²
+    /// n - ddof     i=1
+    /// ```
+    ///
+    /// where `wᵢ` are the winsorized elements and `w̅` is their mean.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty. As in [`SummaryStatisticsExt::var`],
+    /// the result is NaN if `n <= ddof`: noisy float types such as `N64` panic instead, since
+    /// they cannot represent it.
+    ///
+    /// **Panics** if `proportion` is not in `[0, 0.5)`, if `ddof` is less than zero, or if
+    /// `A::from_usize()` fails for `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::RobustStatisticsExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// // Winsorized to [2., 2., 4., 6., 6.], with mean 4.
+    /// let a = array![1., 2., 4., 6., 100.].mapv(n64);
+    /// assert_eq!(a.winsorized_var(0.2, n64(1.))?, n64(4.));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`winsorized_mean`]: #tymethod.winsorized_mean
+    /// [`SummaryStatisticsExt::var`]: trait.SummaryStatisticsExt.html#tymethod.var
+    fn winsorized_var(&self, proportion: f64, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Ord + Float + FromPrimitive;
+
+    /// Returns the winsorized variance of each 1-dimensional lane along `axis`, see
+    /// [`winsorized_var`].
+    ///
+    /// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `proportion` is not in `[0, 0.5)`, if `ddof`
+    /// is less than zero, or if `A::from_usize()` fails for `n`.
+    ///
+    /// [`winsorized_var`]: #tymethod.winsorized_var
+    fn winsorized_var_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Float + FromPrimitive;
+
+    private_decl! {}
+}
+
+impl<A, D> RobustStatisticsExt<A, D> for ArrayRef<A, D>
+where
+    D: Dimension,
+{
+    fn mad<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>,
+    {
+        reduce_all(self, |values| mad(values, scaling, interpolate))
+    }
+
+    fn mad_axis<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>,
+    {
+        reduce_axis(self, axis, |values| mad(values, scaling, interpolate))
+    }
+
+    fn mad_skipnan<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>,
+    {
+        reduce_all_skipnan(self, |values| mad(values, scaling, interpolate))
+    }
+
+    fn mad_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>,
+    {
+        reduce_axis_skipnan(self, axis, |values| mad(values, scaling, interpolate))
+    }
+
+    fn iqr<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>,
+    {
+        reduce_all(self, |values| iqr(values, scaling, interpolate))
+    }
+
+    fn iqr_axis<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A>,
+    {
+        reduce_axis(self, axis, |values| iqr(values, scaling, interpolate))
+    }
+
+    fn iqr_skipnan<I>(&self, scaling: Scaling, interpolate: &I) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>,
+    {
+        reduce_all_skipnan(self, |values| iqr(values, scaling, interpolate))
+    }
+
+    fn iqr_axis_skipnan<I>(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+        interpolate: &I,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+        I: Interpolate<A::NotNan>,
+    {
+        reduce_axis_skipnan(self, axis, |values| iqr(values, scaling, interpolate))
+    }
+
+    fn qn(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + Zero + ToPrimitive + FromPrimitive,
+    {
+        reduce_all(self, |values| qn(values, scaling))
+    }
+
+    fn qn_axis(&self, axis: Axis, scaling: Scaling) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + Zero + ToPrimitive + FromPrimitive,
+    {
+        reduce_axis(self, axis, |values| qn(values, scaling))
+    }
+
+    fn qn_skipnan(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Zero + ToPrimitive + FromPrimitive,
+    {
+        reduce_all_skipnan(self, |values| qn(values, scaling))
+    }
+
+    fn qn_axis_skipnan(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + Zero + ToPrimitive + FromPrimitive,
+    {
+        reduce_axis_skipnan(self, axis, |values| qn(values, scaling))
+    }
+
+    fn sn(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+    {
+        reduce_all(self, |values| sn(values, scaling))
+    }
+
+    fn sn_axis(&self, axis: Axis, scaling: Scaling) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+    {
+        reduce_axis(self, axis, |values| sn(values, scaling))
+    }
+
+    fn sn_skipnan(&self, scaling: Scaling) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+    {
+        reduce_all_skipnan(self, |values| sn(values, scaling))
+    }
+
+    fn sn_axis_skipnan(
+        &self,
+        axis: Axis,
+        scaling: Scaling,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: MaybeNan,
+        A::NotNan: Ord + Clone + Sub<Output = A::NotNan> + ToPrimitive + FromPrimitive,
+    {
+        reduce_axis_skipnan(self, axis, |values| sn(values, scaling))
+    }
+
+    fn trimmed_mean(&self, proportion: f64) -> Result<A, EmptyInput>
+    where
+        A: Ord
+            + Clone
+            + FromPrimitive
+            + Add<Output = A>
+            + Sub<Output = A>
+            + Mul<Output = A>
+            + Div<Output = A>
+            + Zero,
+    {
+        assert_valid_proportion(proportion);
+        reduce_all(self, |values| trimmed_mean(values, proportion))
+    }
+
+    fn trimmed_mean_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord
+            + Clone
+            + FromPrimitive
+            + Add<Output = A>
+            + Sub<Output = A>
+            + Mul<Output = A>
+            + Div<Output = A>
+            + Zero,
+    {
+        assert_valid_proportion(proportion);
+        reduce_axis(self, axis, |values| trimmed_mean(values, proportion))
+    }
+
+    fn winsorized_mean(&self, proportion: f64) -> Result<A, EmptyInput>
+    where
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        assert_valid_proportion(proportion);
+        reduce_all(self, |values| winsorize(values, proportion).mean().unwrap())
+    }
+
+    fn winsorized_mean_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        assert_valid_proportion(proportion);
+        reduce_axis(self, axis, |values| {
+            winsorize(values, proportion).mean().unwrap()
+        })
+    }
+
+    fn winsorized_var(&self, proportion: f64, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Ord + Float + FromPrimitive,
+    {
+        assert_valid_proportion(proportion);
+        reduce_all(self, |values| {
+            var_or_nan(&winsorize(values, proportion), ddof)
+        })
+    }
+
+    fn winsorized_var_axis(
+        &self,
+        axis: Axis,
+        proportion: f64,
+        ddof: A,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        D: RemoveAxis,
+        A: Ord + Float + FromPrimitive,
+    {
+        assert_valid_proportion(proportion);
+        reduce_axis(self, axis, |values| {
+            var_or_nan(&winsorize(values, proportion), ddof)
+        })
+    }
+
+    private_impl! {}
+}
+
+/// Applies `estimator` to a copy of all the elements of `a`.
+///
+/// Returns `Err(EmptyInput)` if `a` is empty.
+fn reduce_all<A, D, F>(a: &ArrayRef<A, D>, estimator: F) -> Result<A, EmptyInput>
+where
+    A: Clone,
+    D: Dimension,
+    F: FnOnce(Array1<A>) -> A,
+{
+    if a.is_empty() {
+        return Err(EmptyInput);
+    }
+    Ok(estimator(a.iter().cloned().collect()))
+}
+
+/// Applies `estimator` to a copy of each 1-dimensional lane of `a` along `axis`.
+///
+/// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+fn reduce_axis<A, D, F>(
+    a: &ArrayRef<A, D>,
+    axis: Axis,
+    mut estimator: F,
+) -> Result<Array<A, D::Smaller>, EmptyInput>
+where
+    A: Clone,
+    D: RemoveAxis,
+    F: FnMut(Array1<A>) -> A,
+{
+    if a.len_of(axis) == 0 {
+        return Err(EmptyInput);
+    }
+    Ok(a.map_axis(axis, |lane| estimator(lane.to_owned())))
+}
+
+/// Applies `estimator` to the elements of `a` that are not NaN, returning NaN if there are none.
+///
+/// Returns `Err(EmptyInput)` if `a` is empty.
+fn reduce_all_skipnan<A, D, F>(a: &ArrayRef<A, D>, estimator: F) -> Result<A, EmptyInput>
+where
+    A: MaybeNan,
+    A::NotNan: Clone,
+    D: Dimension,
+    F: FnOnce(Array1<A::NotNan>) -> A::NotNan,
+{
+    if a.is_empty() {
+        return Err(EmptyInput);
+    }
+    Ok(estimate_skipnan(a.iter(), estimator))
+}
+
+/// Applies `estimator` to the elements of each 1-dimensional lane of `a` along `axis` that are
+/// not NaN, returning NaN for the lanes where there are none.
+///
+/// Returns `Err(EmptyInput)` if the length of `axis` is 0.
+fn reduce_axis_skipnan<A, D, F>(
+    a: &ArrayRef<A, D>,
+    axis: Axis,
+    mut estimator: F,
+) -> Result<Array<A, D::Smaller>, EmptyInput>
+where
+    A: MaybeNan,
+    A::NotNan: Clone,
+    D: RemoveAxis,
+    F: FnMut(Array1<A::NotNan>) -> A::NotNan,
+{
+    if a.len_of(axis) == 0 {
+        return Err(EmptyInput);
+    }
+    Ok(a.map_axis(axis, |lane: ArrayView1<'_, A>| {
+        estimate_skipnan(lane.iter(), &mut estimator)
+    }))
+}
+
+/// Applies `estimator` to the values that are not NaN, returning NaN if there are none.
+fn estimate_skipnan<'a, A, F>(values: impl Iterator<Item = &'a A>, estimator: F) -> A
+where
+    A: MaybeNan + 'a,
+    A::NotNan: Clone,
+    F: FnOnce(Array1<A::NotNan>) -> A::NotNan,
+{
+    let not_nan: Array1<A::NotNan> = values.filter_map(A::try_as_not_nan).cloned().collect();
+    A::from_not_nan_opt(if not_nan.is_empty() {
+        None
+    } else {
+        Some(estimator(not_nan))
+    })
+}
+
+/// **Panics** if `proportion` is not in `[0, 0.5)`.
+fn assert_valid_proportion(proportion: f64) {
+    assert!(
+        (0. ..0.5).contains(&proportion),
+        "`proportion` needs to be in [0, 0.5)."
+    );
+}
+
+/// Returns `g = ⌊proportion · n⌋` and the `(g + 1)`-th smallest and largest of non-empty
+/// `values`.
+fn winsorizing_limits<A>(values: &mut Array1<A>, proportion: f64) -> (usize, A, A)
+where
+    A: Ord + Clone,
+{
+    let n = values.len();
+    let g = (proportion * n as f64).floor() as usize;
+    let limits = values.get_many_from_sorted_mut(&aview1(&[g, n - 1 - g]));
+    (g, limits[&g].clone(), limits[&(n - 1 - g)].clone())
+}
+
+/// Returns non-empty `values`, winsorized by `proportion` on each tail.
+fn winsorize<A>(mut values: Array1<A>, proportion: f64) -> Array1<A>
+where
+    A: Ord + Clone,
+{
+    let (_, low, high) = winsorizing_limits(&mut values, proportion);
+    values.mapv_into(|value| value.clamp(low.clone(), high.clone()))
+}
+
+/// Returns the mean of non-empty `values`, trimmed by `proportion` on each tail.
+fn trimmed_mean<A>(mut values: Array1<A>, proportion: f64) -> A
+where
+    A: Ord
+        + Clone
+        + FromPrimitive
+        + Add<Output = A>
+        + Sub<Output = A>
+        + Mul<Output = A>
+        + Div<Output = A>
+        + Zero,
+{
+    let n = values.len();
+    let (g, low, high) = winsorizing_limits(&mut values, proportion);
+    // The winsorized elements are the trimmed ones, plus `g` times each limit
+    let winsorized_sum = values.fold(A::zero(), |sum, value| {
+        sum + value.clone().clamp(low.clone(), high.clone())
+    });
+    let n_kept =
+        A::from_usize(n - 2 * g).expect("Converting number of kept elements to `A` must not fail.");
+    let g = A::from_usize(g).expect("Converting number of trimmed elements to `A` must not fail.");
+    (winsorized_sum - g.clone() * low - g * high) / n_kept
+}
+
+/// Returns `|a - b|`.
+fn abs_diff<A: Ord + Sub<Output = A>>(a: A, b: A) -> A {
+    if a >= b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Returns the median absolute deviation of non-empty `values`.
+fn mad<A, I>(mut values: Array1<A>, scaling: Scaling, interpolate: &I) -> A
+where
+    A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+    I: Interpolate<A>,
+{
+    let median = values.quantile_mut(n64(0.5), interpolate).unwrap();
+    let mut deviations = values.mapv_into(|value| abs_diff(value, median.clone()));
+    let mad = deviations.quantile_mut(n64(0.5), interpolate).unwrap();
+    scaling.apply(mad, MAD_NORMAL_FACTOR)
+}
+
+/// Returns the interquartile range of non-empty `values`.
+fn iqr<A, I>(mut values: Array1<A>, scaling: Scaling, interpolate: &I) -> A
+where
+    A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+    I: Interpolate<A>,
+{
+    let quartiles = values
+        .quantiles_mut(&Array1::from(vec![n64(0.25), n64(0.75)]), interpolate)
+        .unwrap();
+    let iqr = quartiles[1].clone() - quartiles[0].clone();
+    scaling.apply(iqr, IQR_NORMAL_FACTOR)
+}
+
+/// Returns the Qn estimator of scale of non-empty `values`.
+///
+/// The `k`-th smallest distance is selected without computing all of them, following Croux and
+/// Rousseeuw, "Time-efficient algorithms for two highly robust estimators of scale" (1992):
+/// once the values are sorted, the distances `xⱼ - xᵢ` with `i < j` form the upper triangle of
+/// a matrix whose rows are sorted. Each row keeps a range of candidate columns, and each
+/// iteration counts the distances below a pivot, the weighted median of the middle candidates
+/// of the rows, in `O(n)` time: at least a quarter of the candidates are discarded at every
+/// iteration, which takes `O(n log n)` time and `O(n)` memory overall.
+fn qn<A>(values: Array1<A>, scaling: Scaling) -> A
+where
+    A: Ord + Clone + Sub<Output = A> + Zero + ToPrimitive + FromPrimitive,
+{
+    let n = values.len();
+    if n == 1 {
+        return A::zero();
+    }
+    let mut x = values.to_vec();
+    x.sort_unstable();
+    let distance = |i: usize, j: usize| x[j].clone() - x[i].clone();
+    let h = n / 2 + 1;
+    let k = h * (h - 1) / 2;
+    // The candidates of row `i` are the distances to `x[j]` for `j` in `left[i]..right[i]`;
+    // `n_below` distances, outside of these ranges, are known to be smaller than the Qn
+    let mut left: Vec<usize> = (1..=n).collect();
+    let mut right = vec![n; n];
+    let mut n_below = 0;
+    loop {
+        let n_candidates: usize = left.iter().zip(&right).map(|(l, r)| r - l).sum();
+        if n_candidates <= n {
+            let mut candidates: Array1<A> = (0..n)
+                .flat_map(|i| (left[i]..right[i]).map(move |j| (i, j)))
+                .map(|(i, j)| distance(i, j))
+                .collect();
+            let qn = candidates.get_from_sorted_mut(k - n_below - 1);
+            return scaling.apply(qn, QN_NORMAL_FACTOR);
+        }
+        let middles = (0..n)
+            .filter(|&i| left[i] < right[i])
+            .map(|i| {
+                let width = right[i] - left[i];
+                (distance(i, left[i] + width / 2), width)
+            })
+            .collect();
+        let pivot = weighted_median(middles);
+        // `below[i]` (resp. `not_above[i]`) is the first column of row `i` whose distance is not
+        // below (resp. is above) the pivot: it does not decrease with `i`
+        let (mut below, mut not_above) = (Vec::with_capacity(n), Vec::with_capacity(n));
+        let (mut j_below, mut j_not_above) = (0, 0);
+        for i in 0..n {
+            j_below = j_below.max(i + 1);
+            while j_below < n && distance(i, j_below) < pivot {
+                j_below += 1;
+            }
+            j_not_above = j_not_above.max(i + 1);
+            while j_not_above < n && distance(i, j_not_above) <= pivot {
+                j_not_above += 1;
+            }
+            below.push(j_below);
+            not_above.push(j_not_above);
+        }
+        let n_pivot_below: usize = below.iter().enumerate().map(|(i, j)| j - (i + 1)).sum();
+        let n_pivot_not_above: usize = not_above.iter().enumerate().map(|(i, j)| j - (i + 1)).sum();
+        if k <= n_pivot_below {
+            for (right, below) in right.iter_mut().zip(below) {
+                *right = (*right).min(below);
+            }
+        } else if k > n_pivot_not_above {
+            for (left, not_above) in left.iter_mut().zip(not_above) {
+                *left = (*left).max(not_above);
+            }
+            n_below = left.iter().enumerate().map(|(i, j)| j - (i + 1)).sum();
+        } else {
+            return scaling.apply(pivot, QN_NORMAL_FACTOR);
+        }
+    }
+}
+
+/// Returns the weighted median of non-empty `values`, i.e. the smallest value such that the
+/// values up to it account for at least half of the total weight, in `O(n)` time on average.
+fn weighted_median<A: Ord + Clone>(mut values: Vec<(A, usize)>) -> A {
+    let total_weight: usize = values.iter().map(|(_, weight)| weight).sum();
+    let mut needed_weight = (total_weight + 1) / 2;
+    let mut values = &mut values[..];
+    loop {
+        let middle = values.len() / 2;
+        let (lower, (median, weight), upper) =
+            std::mem::take(&mut values).select_nth_unstable_by(middle, |(a, _), (b, _)| a.cmp(b));
+        let lower_weight: usize = lower.iter().map(|(_, weight)| weight).sum();
+        if lower_weight >= needed_weight {
+            values = lower;
+        } else if lower_weight + *weight >= needed_weight {
+            return median.clone();
+        } else {
+            needed_weight -= lower_weight + *weight;
+            values = upper;
+        }
+    }
+}
+
+/// Returns the Sn estimator of scale of non-empty `values`.
+fn sn<A>(values: Array1<A>, scaling: Scaling) -> A
+where
+    A: Ord + Clone + Sub<Output = A> + ToPrimitive + FromPrimitive,
+{
+    let n = values.len();
+    let mut distances = Array1::from_elem(n, values[0].clone());
+    let mut high_medians: Array1<A> = values
+        .iter()
+        .map(|x_i| {
+            distances.zip_mut_with(&values, |distance, x_j| {
+                *distance = abs_diff(x_i.clone(), x_j.clone());
+            });
+            distances.get_from_sorted_mut(n / 2)
+        })
+        .collect();
+    let sn = high_medians.get_from_sorted_mut((n + 1) / 2 - 1);
+    scaling.apply(sn, SN_NORMAL_FACTOR)
+}
@@ -0,0 +1,313 @@
+use super::means::{central_moment_coefficients, horner_method, moments};
+use crate::errors::EmptyInput;
+use ndarray::{ArrayRef, Dimension};
+use num_integer::binomial;
+use num_traits::{Float, FromPrimitive};
+
+/// A mergeable accumulator of the mean and of the first *p* [central moments] of a stream of
+/// observations.
+///
+/// Observations can be pushed one at a time ([`push`]), one chunk at a time ([`push_array`]),
+/// and accumulators built over separate partitions of the data can be combined with [`merge`].
+/// The results are therefore available without ever holding the whole dataset in memory.
+///
+/// Each chunk passed to [`push_array`] is summarised with the same corrected two-pass algorithm
+/// used by [`SummaryStatisticsExt::central_moments`]; partial results are then combined using
+/// the pairwise update formulas in [Pébay, 2008]. Feeding a single chunk into an empty
+/// accumulator yields exactly the same moments as calling
+/// [`SummaryStatisticsExt::central_moments`] on it.
+///
+/// # Example
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use ndarray::array;
+/// use ndarray_stats::{MomentsAccumulator, SummaryStatisticsExt};
+///
+/// let data = array![1., 4., 2., 8., 5., 7.];
+///
+/// let mut first = MomentsAccumulator::new(4);
+/// first.push_array(&data.slice(ndarray::s![..4]));
+/// let mut second = MomentsAccumulator::new(4);
+/// for &x in data.slice(ndarray::s![4..]) {
+///     second.push(x);
+/// }
+/// first.merge(&second);
+///
+/// assert_eq!(first.count(), 6);
+/// assert_abs_diff_eq!(first.mean().unwrap(), 4.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(first.kurtosis().unwrap(), data.kurtosis().unwrap(), epsilon = 1e-12);
+/// ```
+///
+/// [central moments]: https://en.wikipedia.org/wiki/Central_moment
+/// [`push`]: #method.push
+/// [`push_array`]: #method.push_array
+/// [`merge`]: #method.merge
+/// [`SummaryStatisticsExt::central_moments`]: trait.SummaryStatisticsExt.html#tymethod.central_moments
+/// [Pébay, 2008]: https://www.osti.gov/servlets/purl/1028931
+#[derive(Clone, Debug, PartialEq)]
+pub struct MomentsAccumulator<A> {
+    order: u16,
+    count: usize,
+    mean: A,
+    /// Central moments of order `0..=order` of the observations seen so far. The first two
+    /// entries are always `1` and `0`.
+    central_moments: Vec<A>,
+}
+
+impl<A> MomentsAccumulator<A>
+where
+    A: Float + FromPrimitive,
+{
+    /// Returns an empty accumulator tracking the central moments up to `order` (included).
+    pub fn new(order: u16) -> Self {
+        let mut central_moments = vec![A::zero(); usize::from(order) + 1];
+        central_moments[0] = A::one();
+        MomentsAccumulator {
+            order,
+            count: 0,
+            mean: A::zero(),
+            central_moments,
+        }
+    }
+
+    /// Returns the highest order of the central moments tracked by the accumulator.
+    pub fn order(&self) -> u16 {
+        self.order
+    }
+
+    /// Returns the number of observations seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no observations have been seen so far.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Adds a single observation to the accumulator.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of observations.
+    pub fn push(&mut self, value: A) {
+        if self.is_empty() {
+            self.count = 1;
+            self.mean = value;
+        } else {
+            self.merge_parts(1, value, None);
+        }
+    }
+
+    /// Adds all the elements of `array` to the accumulator.
+    ///
+    /// The central moments of `array` are computed with the same corrected two-pass algorithm
+    /// used by [`SummaryStatisticsExt::central_moments`], and then merged into the accumulator.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of observations.
+    ///
+    /// [`SummaryStatisticsExt::central_moments`]: trait.SummaryStatisticsExt.html#tymethod.central_moments
+    pub fn push_array<D>(&mut self, array: &ArrayRef<A, D>)
+    where
+        D: Dimension,
+    {
+        let count = array.len();
+        if count == 0 {
+            return;
+        }
+        let n_elements =
+            A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        let mean = array.sum() / n_elements;
+
+        let mut central_moments = vec![A::one(), A::zero()];
+        central_moments.truncate(usize::from(self.order) + 1);
+        if self.order >= 2 {
+            let shifted_array = array.mapv(|x| x - mean);
+            let shifted_moments = moments(shifted_array, self.order);
+            let correction_term = -shifted_moments[1];
+            for k in 2..=usize::from(self.order) {
+                let coefficients = central_moment_coefficients(&shifted_moments[..=k]);
+                central_moments.push(horner_method(coefficients, correction_term));
+            }
+        }
+
+        if self.is_empty() {
+            self.count = count;
+            self.mean = mean;
+            self.central_moments = central_moments;
+        } else {
+            self.merge_parts(count, mean, Some(&central_moments));
+        }
+    }
+
+    /// Merges the observations summarised by `other` into `self`.
+    ///
+    /// After merging, `self` holds the same statistics it would have if all the observations
+    /// pushed into `other` had been pushed into `self` instead.
+    ///
+    /// **Panics** if `self` and `other` do not track the same order of central moments, or if
+    /// `A::from_usize()` fails to convert the number of observations.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.order, other.order,
+            "Cannot merge accumulators tracking central moments of different orders."
+        );
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.clone_from(other);
+            return;
+        }
+        self.merge_parts(other.count, other.mean, Some(&other.central_moments));
+    }
+
+    /// Combines the statistics in `self` with those of a non-empty set of `count_b`
+    /// observations, with mean `mean_b` and central moments `central_moments_b`.
+    ///
+    /// `None` stands for a set whose central moments of order 2 and higher are all zero
+    /// (e.g. a single observation).
+    ///
+    /// See equation 3.1 in [Pébay, 2008](https://www.osti.gov/servlets/purl/1028931).
+    fn merge_parts(&mut self, count_b: usize, mean_b: A, central_moments_b: Option<&[A]>) {
+        let count_a = self.count;
+        let count = count_a + count_b;
+        let n_a =
+            A::from_usize(count_a).expect("Converting number of elements to `A` must not fail.");
+        let n_b =
+            A::from_usize(count_b).expect("Converting number of elements to `A` must not fail.");
+        let n = n_a + n_b;
+        let delta = mean_b - self.mean;
+
+        // Power sums of the deviations from the mean, i.e. `n` times the central moments.
+        let sum_a = |k: usize| self.central_moments[k] * n_a;
+        let sum_b = |k: usize| central_moments_b.map_or(A::zero(), |m| m[k] * n_b);
+
+        let mut updated = self.central_moments[..usize::from(self.order.min(1)) + 1].to_vec();
+        for p in 2..=usize::from(self.order) {
+            let mut sum = sum_a(p) + sum_b(p);
+            for k in 1..=(p - 2) {
+                let k_i32 = k as i32;
+                let binom = A::from_usize(binomial(p, k)).unwrap();
+                sum = sum
+                    + binom
+                        * delta.powi(k_i32)
+                        * ((-n_b / n).powi(k_i32) * sum_a(p - k)
+                            + (n_a / n).powi(k_i32) * sum_b(p - k));
+            }
+            let p_i32 = p as i32;
+            sum = sum
+                + (n_a * n_b * delta / n).powi(p_i32)
+                    * (n_b.powi(1 - p_i32) - (-n_a.recip()).powi(p_i32 - 1));
+            updated.push(sum / n);
+        }
+
+        self.count = count;
+        self.mean = self.mean + delta * n_b / n;
+        self.central_moments = updated;
+    }
+
+    /// Returns the [arithmetic mean] of the observations seen so far.
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    pub fn mean(&self) -> Result<A, EmptyInput> {
+        if self.is_empty() {
+            Err(EmptyInput)
+        } else {
+            Ok(self.mean)
+        }
+    }
+
+    /// Returns the *p*-th [central moment] of the observations seen so far, with the same
+    /// definition used by [`SummaryStatisticsExt::central_moment`].
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `order` is greater than the order tracked by the accumulator.
+    ///
+    /// [central moment]: https://en.wikipedia.org/wiki/Central_moment
+    /// [`SummaryStatisticsExt::central_moment`]: trait.SummaryStatisticsExt.html#tymethod.central_moment
+    pub fn central_moment(&self, order: u16) -> Result<A, EmptyInput> {
+        assert!(
+            order <= self.order,
+            "The accumulator only tracks central moments up to order {}.",
+            self.order
+        );
+        if self.is_empty() {
+            Err(EmptyInput)
+        } else {
+            Ok(self.central_moments[usize::from(order)])
+        }
+    }
+
+    /// Returns all the central moments tracked by the accumulator, ordered by power magnitude,
+    /// with the same layout returned by [`SummaryStatisticsExt::central_moments`].
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// [`SummaryStatisticsExt::central_moments`]: trait.SummaryStatisticsExt.html#tymethod.central_moments
+    pub fn central_moments(&self) -> Result<Vec<A>, EmptyInput> {
+        if self.is_empty() {
+            Err(EmptyInput)
+        } else {
+            Ok(self.central_moments.clone())
+        }
+    }
+
+    /// Returns the variance of the observations seen so far.
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate
+    /// the population variance, use `ddof = 0`, or to calculate the sample variance, use
+    /// `ddof = 1`.
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the accumulator tracks central moments of order lower than 2, or if `ddof`
+    /// is negative or not smaller than the number of observations.
+    pub fn var(&self, ddof: A) -> Result<A, EmptyInput> {
+        let second_moment = self.central_moment(2)?;
+        let n =
+            A::from_usize(self.count).expect("Converting number of elements to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof >= n),
+            "`ddof` must not be less than zero or greater than or equal to the number of observations",
+        );
+        Ok(second_moment * n / (n - ddof))
+    }
+
+    /// Returns the standard deviation of the observations seen so far, see [`var`] for details.
+    ///
+    /// [`var`]: #method.var
+    pub fn std(&self, ddof: A) -> Result<A, EmptyInput> {
+        self.var(ddof).map(A::sqrt)
+    }
+
+    /// Returns the [Pearson's moment coefficient of skewness] γ₁ of the observations seen so
+    /// far, with the same definition used by [`SummaryStatisticsExt::skewness`].
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the accumulator tracks central moments of order lower than 3.
+    ///
+    /// [Pearson's moment coefficient of skewness]: https://en.wikipedia.org/wiki/Skewness
+    /// [`SummaryStatisticsExt::skewness`]: trait.SummaryStatisticsExt.html#tymethod.skewness
+    pub fn skewness(&self) -> Result<A, EmptyInput> {
+        let third_moment = self.central_moment(3)?;
+        Ok(third_moment / self.central_moments[2].sqrt().powi(3))
+    }
+
+    /// Returns the [kurtosis] `Kurt[X]` of the observations seen so far, with the same
+    /// definition used by [`SummaryStatisticsExt::kurtosis`].
+    ///
+    /// If no observations have been seen, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the accumulator tracks central moments of order lower than 4.
+    ///
+    /// [kurtosis]: https://en.wikipedia.org/wiki/Kurtosis
+    /// [`SummaryStatisticsExt::kurtosis`]: trait.SummaryStatisticsExt.html#tymethod.kurtosis
+    pub fn kurtosis(&self) -> Result<A, EmptyInput> {
+        let fourth_moment = self.central_moment(4)?;
+        Ok(fourth_moment / self.central_moments[2].powi(2))
+    }
+}
@@ -1,5 +1,10 @@
+#[cfg(feature = "rayon")]
+use super::MomentsAccumulator;
 use super::SummaryStatisticsExt;
 use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
+use crate::{MaybeNan, MaybeNanExt};
+#[cfg(feature = "rayon")]
+use ndarray::ArrayView;
 use ndarray::{Array, ArrayBase, ArrayRef, Axis, Data, Dimension, Ix1, RemoveAxis};
 use num_integer::IterBinomial;
 use num_traits::{Float, FromPrimitive, Zero};
@@ -172,6 +177,143 @@ where
             .mapv_into(|x| x.sqrt()))
     }
 
+    fn var(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        assert_non_negative_ddof(ddof);
+        Ok(self
+            .fold(Welford::new(), |welford, &x| welford.update(x))
+            .var_or_nan(ddof))
+    }
+
+    fn var_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(EmptyInput);
+        }
+        assert_non_negative_ddof(ddof);
+        Ok(self.map_axis(axis, |lane| {
+            lane.fold(Welford::new(), |welford, &x| welford.update(x))
+                .var_or_nan(ddof)
+        }))
+    }
+
+    fn std(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        SummaryStatisticsExt::var(self, ddof).map(A::sqrt)
+    }
+
+    fn std_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        SummaryStatisticsExt::var_axis(self, axis, ddof).map(|var| var.mapv_into(A::sqrt))
+    }
+
+    fn sem(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let n =
+            A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        SummaryStatisticsExt::var(self, ddof).map(|var| (var / n).sqrt())
+    }
+
+    fn sem_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = A::from_usize(self.len_of(axis))
+            .expect("Converting number of elements to `A` must not fail.");
+        SummaryStatisticsExt::var_axis(self, axis, ddof)
+            .map(|var| var.mapv_into(|var| (var / n).sqrt()))
+    }
+
+    fn mean_skipnan(&self) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(welford_skipnan(self).mean_or_nan())
+    }
+
+    fn mean_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        Ok(welford_axis_skipnan(self, axis)?.map(|welford| welford.mean_or_nan()))
+    }
+
+    fn var_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        assert_non_negative_ddof(ddof);
+        Ok(welford_skipnan(self).var_or_nan(ddof))
+    }
+
+    fn var_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        assert_non_negative_ddof(ddof);
+        Ok(welford_axis_skipnan(self, axis)?.map(|welford| welford.var_or_nan(ddof)))
+    }
+
+    fn std_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        self.var_skipnan(ddof).map(A::sqrt)
+    }
+
+    fn std_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.var_axis_skipnan(axis, ddof)
+            .map(|var| var.mapv_into(A::sqrt))
+    }
+
+    fn sem_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        assert_non_negative_ddof(ddof);
+        Ok(welford_skipnan(self).sem_or_nan(ddof))
+    }
+
+    fn sem_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        assert_non_negative_ddof(ddof);
+        Ok(welford_axis_skipnan(self, axis)?.map(|welford| welford.sem_or_nan(ddof)))
+    }
+
     fn kurtosis(&self) -> Result<A, EmptyInput>
     where
         A: Float + FromPrimitive,
@@ -240,9 +382,220 @@ where
         }
     }
 
+    #[cfg(feature = "rayon")]
+    fn par_mean(&self) -> Result<A, EmptyInput>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero + Send + Sync,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            Err(EmptyInput)
+        } else {
+            let n_elements = A::from_usize(n_elements)
+                .expect("Converting number of elements to `A` must not fail.");
+            let sum = par_reduce(self.view(), &|block| block.sum(), &|a, b| a + b);
+            Ok(sum / n_elements)
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_kurtosis(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync,
+    {
+        let central_moments = self.par_central_moments(4)?;
+        Ok(central_moments[4] / central_moments[2].powi(2))
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_skewness(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync,
+    {
+        let central_moments = self.par_central_moments(3)?;
+        Ok(central_moments[3] / central_moments[2].sqrt().powi(3))
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_central_moment(&self, order: u16) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync,
+    {
+        self.par_central_moments(order)
+            .map(|central_moments| central_moments[usize::from(order)])
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_central_moments(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync,
+    {
+        let accumulator = par_reduce(
+            self.view(),
+            &|block| {
+                let mut accumulator = MomentsAccumulator::new(order);
+                accumulator.push_array(&block);
+                accumulator
+            },
+            &|mut left, right| {
+                left.merge(&right);
+                left
+            },
+        );
+        accumulator.central_moments()
+    }
+
     private_impl! {}
 }
 
+/// Minimum number of elements in a block processed by a single task in `par_reduce`.
+#[cfg(feature = "rayon")]
+const PAR_MIN_BLOCK_LEN: usize = 1 << 14;
+
+/// Recursively halves `view` along its longest axis until blocks have at most
+/// `PAR_MIN_BLOCK_LEN` elements, applies `map` to each block in parallel and combines
+/// the partial results pairwise with `reduce`.
+///
+/// The shape of the reduction tree only depends on the shape of `view`, so results
+/// are reproducible across runs and thread pool sizes.
+#[cfg(feature = "rayon")]
+fn par_reduce<A, D, T, M, R>(view: ArrayView<'_, A, D>, map: &M, reduce: &R) -> T
+where
+    A: Sync,
+    D: Dimension,
+    T: Send,
+    M: Fn(ArrayView<'_, A, D>) -> T + Sync,
+    R: Fn(T, T) -> T + Sync,
+{
+    if view.len() <= PAR_MIN_BLOCK_LEN {
+        return map(view);
+    }
+    // `view` has more than one element, so it has at least one axis of length 2 or more.
+    let axis = (0..view.ndim())
+        .map(Axis)
+        .max_by_key(|&axis| view.len_of(axis))
+        .unwrap();
+    let mid = view.len_of(axis) / 2;
+    let (left, right) = view.split_at(axis, mid);
+    let (left, right) = rayon::join(
+        || par_reduce(left, map, reduce),
+        || par_reduce(right, map, reduce),
+    );
+    reduce(left, right)
+}
+
+/// Number of observations, mean and sum of squared deviations from the mean, updated with
+/// Welford's online algorithm.
+#[derive(Clone, Copy)]
+struct Welford<A> {
+    count: usize,
+    mean: A,
+    m2: A,
+}
+
+impl<A> Welford<A>
+where
+    A: Float + FromPrimitive,
+{
+    fn new() -> Self {
+        Welford {
+            count: 0,
+            mean: A::zero(),
+            m2: A::zero(),
+        }
+    }
+
+    fn update(self, x: A) -> Self {
+        let count = self.count + 1;
+        let n = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        let delta = x - self.mean;
+        let mean = self.mean + delta / n;
+        Welford {
+            count,
+            mean,
+            m2: self.m2 + delta * (x - mean),
+        }
+    }
+
+    fn n(&self) -> A {
+        A::from_usize(self.count).expect("Converting number of elements to `A` must not fail.")
+    }
+
+    fn var(&self, ddof: A) -> A {
+        self.m2 / (self.n() - ddof)
+    }
+
+    fn mean_or_nan(&self) -> A {
+        if self.count == 0 {
+            A::nan()
+        } else {
+            self.mean
+        }
+    }
+
+    fn var_or_nan(&self, ddof: A) -> A {
+        if self.n() <= ddof {
+            A::nan()
+        } else {
+            self.var(ddof)
+        }
+    }
+
+    fn sem_or_nan(&self, ddof: A) -> A {
+        (self.var_or_nan(ddof) / self.n()).sqrt()
+    }
+}
+
+/// Runs Welford's online algorithm over the elements of `arr` that are not NaN.
+fn welford_skipnan<A, D>(arr: &ArrayRef<A, D>) -> Welford<A>
+where
+    A: MaybeNan + Float + FromPrimitive,
+    D: Dimension,
+{
+    arr.fold_skipnan(Welford::new(), |welford, x| {
+        welford.update(*A::from_not_nan_ref_opt(Some(x)))
+    })
+}
+
+/// Runs Welford's online algorithm over the elements of each lane of `arr` along `axis` that
+/// are not NaN.
+fn welford_axis_skipnan<A, D>(
+    arr: &ArrayRef<A, D>,
+    axis: Axis,
+) -> Result<Array<Welford<A>, D::Smaller>, EmptyInput>
+where
+    A: MaybeNan + Float + FromPrimitive,
+    D: RemoveAxis,
+{
+    if arr.len_of(axis) == 0 {
+        return Err(EmptyInput);
+    }
+    Ok(arr.fold_axis_skipnan(axis, Welford::new(), |welford, x| {
+        welford.update(*A::from_not_nan_ref_opt(Some(x)))
+    }))
+}
+
+/// **Panics** if `ddof` is less than zero.
+fn assert_non_negative_ddof<A: Float>(ddof: A) {
+    assert!(ddof >= A::zero(), "`ddof` must not be less than zero");
+}
+
+/// Returns the variance of `values` computed with Welford's online algorithm, or NaN if their
+/// number `n` is not greater than `ddof`, as in [`SummaryStatisticsExt::var`].
+///
+/// **Panics** if `ddof` is less than zero.
+pub(crate) fn var_or_nan<'a, A, I>(values: I, ddof: A) -> A
+where
+    A: 'a + Float + FromPrimitive,
+    I: IntoIterator<Item = &'a A>,
+{
+    assert_non_negative_ddof(ddof);
+    values
+        .into_iter()
+        .fold(Welford::new(), |welford, &x| welford.update(x))
+        .var_or_nan(ddof)
+}
+
 /// Private function for `weighted_var` without conditions and asserts.
 fn inner_weighted_var<A, D>(
     arr: &ArrayRef<A, D>,
@@ -278,7 +631,7 @@ where
 /// The returned moments are ordered by power magnitude: 0th moment, 1st moment, etc.
 ///
 /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
-fn moments<A, S, D>(a: ArrayBase<S, D>, order: u16) -> Vec<A>
+pub(super) fn moments<A, S, D>(a: ArrayBase<S, D>, order: u16) -> Vec<A>
 where
     A: Float + FromPrimitive,
     S: Data<Elem = A>,
@@ -308,7 +661,7 @@ where
 ///
 /// It takes as input all moments up to order *p*, ordered by power magnitude - *p* is
 /// inferred to be the length of the *moments* array.
-fn central_moment_coefficients<A>(moments: &[A]) -> Vec<A>
+pub(super) fn central_moment_coefficients<A>(moments: &[A]) -> Vec<A>
 where
     A: Float + FromPrimitive,
 {
@@ -331,7 +684,7 @@ where
 /// this is an optimal algorithm for polynomial evaluation.
 ///
 /// [Horner's method]: https://en.wikipedia.org/wiki/Horner%27s_method
-fn horner_method<A>(coefficients: Vec<A>, indeterminate: A) -> A
+pub(super) fn horner_method<A>(coefficients: Vec<A>, indeterminate: A) -> A
 where
     A: Float,
 {
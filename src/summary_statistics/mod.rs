@@ -1,5 +1,8 @@
 //! Summary statistics (e.g. mean, variance, etc.).
+pub use self::accumulator::MomentsAccumulator;
+pub(crate) use self::means::var_or_nan;
 use crate::errors::{EmptyInput, MultiInputError};
+use crate::MaybeNan;
 use ndarray::{Array, ArrayRef, Axis, Dimension, Ix1, RemoveAxis};
 use num_traits::{Float, FromPrimitive, Zero};
 use std::ops::{Add, AddAssign, Div, Mul};
@@ -231,6 +234,248 @@ where
         A: AddAssign + Float + FromPrimitive,
         D: RemoveAxis;
 
+    /// Returns the [variance] of all elements in the array:
+    ///
+    /// ```text
+    ///         1       n
+    /// σ² = ――――――――   ∑ (xᵢ - x̅)²
+    ///      n - ddof  i=1
+    /// ```
+    ///
+    /// computed with [Welford's online algorithm].
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom". For example, to calculate the
+    /// population variance, use `ddof = 0`, or to calculate the sample variance, use `ddof = 1`.
+    ///
+    /// The inherent `ArrayRef::var` method takes precedence with the method call syntax: call
+    /// this one as `SummaryStatisticsExt::var(&*a, ddof)`.
+    ///
+    /// If the number of elements `n` is not greater than `ddof`, NaN is returned, as in
+    /// [`var_skipnan`].
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of elements.
+    ///
+    /// [variance]: https://en.wikipedia.org/wiki/Variance
+    /// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+    /// [`var_skipnan`]: #tymethod.var_skipnan
+    fn var(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [variance] along `axis`, see [`var`].
+    ///
+    /// The inherent `ArrayRef::var_axis` method takes precedence with the method call syntax:
+    /// call this one as `SummaryStatisticsExt::var_axis(&*a, axis, ddof)`.
+    ///
+    /// If the length of `axis` is not greater than `ddof`, the variance of each lane is NaN.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the length of `axis`.
+    ///
+    /// [variance]: https://en.wikipedia.org/wiki/Variance
+    /// [`var`]: #tymethod.var
+    fn var_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the standard deviation of all elements in the array, i.e. the square root of
+    /// the [`var`].
+    ///
+    /// The inherent `ArrayRef::std` method takes precedence with the method call syntax: call
+    /// this one as `SummaryStatisticsExt::std(&*a, ddof)`.
+    ///
+    /// If the number of elements `n` is not greater than `ddof`, NaN is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of elements.
+    ///
+    /// [`var`]: #tymethod.var
+    fn std(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the standard deviation along `axis`, see [`std`].
+    ///
+    /// The inherent `ArrayRef::std_axis` method takes precedence with the method call syntax:
+    /// call this one as `SummaryStatisticsExt::std_axis(&*a, axis, ddof)`.
+    ///
+    /// If the length of `axis` is not greater than `ddof`, the standard deviation of each lane
+    /// is NaN.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the length of `axis`.
+    ///
+    /// [`std`]: #tymethod.std
+    fn std_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the [standard error of the mean] of all elements in the array:
+    ///
+    /// ```text
+    ///        σ
+    /// SEM = ――
+    ///       √n
+    /// ```
+    ///
+    /// where σ is the standard deviation computed with `ddof`, see [`var`].
+    ///
+    /// If the number of elements `n` is not greater than `ddof`, NaN is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of elements.
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    /// [`var`]: #tymethod.var
+    fn sem(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [standard error of the mean] along `axis`, see [`sem`].
+    ///
+    /// If the length of `axis` is not greater than `ddof`, the standard error of each lane is
+    /// NaN.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the length of `axis`.
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    /// [`sem`]: #tymethod.sem
+    fn sem_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the [`arithmetic mean`] of all elements in the array, skipping NaN values.
+    ///
+    /// If all the elements are NaN, NaN is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of non-NaN elements.
+    ///
+    /// [`arithmetic mean`]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    fn mean_skipnan(&self) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Returns the [`arithmetic mean`] along `axis`, skipping NaN values.
+    ///
+    /// The mean of the lanes where all the elements are NaN is NaN.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails to convert the
+    /// number of non-NaN elements of a lane.
+    ///
+    /// [`arithmetic mean`]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    fn mean_axis_skipnan(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the variance of all elements in the array, skipping NaN values, see [`var`].
+    ///
+    /// If the number of non-NaN elements `n` is not greater than `ddof` (e.g. if all the
+    /// elements are NaN), NaN is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of non-NaN elements.
+    ///
+    /// [`var`]: #tymethod.var
+    fn var_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Returns the variance along `axis`, skipping NaN values, see [`var`].
+    ///
+    /// The variance of the lanes where the number of non-NaN elements `n` is not greater than
+    /// `ddof` is NaN.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the number of non-NaN elements of a lane.
+    ///
+    /// [`var`]: #tymethod.var
+    fn var_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the standard deviation of all elements in the array, skipping NaN values, see
+    /// [`var_skipnan`].
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of non-NaN elements.
+    ///
+    /// [`var_skipnan`]: #tymethod.var_skipnan
+    fn std_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Returns the standard deviation along `axis`, skipping NaN values, see
+    /// [`var_axis_skipnan`].
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the number of non-NaN elements of a lane.
+    ///
+    /// [`var_axis_skipnan`]: #tymethod.var_axis_skipnan
+    fn std_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Returns the standard error of the mean of all elements in the array, skipping NaN
+    /// values, see [`sem`] and [`var_skipnan`]. `n` is the number of non-NaN elements.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, or if `A::from_usize()` fails to convert the
+    /// number of non-NaN elements.
+    ///
+    /// [`sem`]: #tymethod.sem
+    /// [`var_skipnan`]: #tymethod.var_skipnan
+    fn sem_skipnan(&self, ddof: A) -> Result<A, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive;
+
+    /// Returns the standard error of the mean along `axis`, skipping NaN values, see [`sem`]
+    /// and [`var_axis_skipnan`]. `n` is the number of non-NaN elements of each lane.
+    ///
+    /// If the length of `axis` is 0, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ddof` is less than zero, if `axis` is out of bounds, or if
+    /// `A::from_usize()` fails to convert the number of non-NaN elements of a lane.
+    ///
+    /// [`sem`]: #tymethod.sem
+    /// [`var_axis_skipnan`]: #tymethod.var_axis_skipnan
+    fn sem_axis_skipnan(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: MaybeNan + Float + FromPrimitive,
+        D: RemoveAxis;
+
     /// Returns the [kurtosis] `Kurt[X]` of all elements in the array:
     ///
     /// ```text
@@ -310,7 +555,73 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Parallel version of [`mean`](#tymethod.mean).
+    ///
+    /// The array is recursively split into blocks that are summed on the `rayon` thread pool.
+    /// Partial sums are added in a different order than in the sequential version, hence
+    /// results for floating point elements can differ within the tolerance documented
+    /// in the [crate-level documentation](index.html#crate-features).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_mean(&self) -> Result<A, EmptyInput>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero + Send + Sync;
+
+    /// Parallel version of [`kurtosis`](#tymethod.kurtosis), see
+    /// [`par_central_moments`](#tymethod.par_central_moments) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_kurtosis(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync;
+
+    /// Parallel version of [`skewness`](#tymethod.skewness), see
+    /// [`par_central_moments`](#tymethod.par_central_moments) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_skewness(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync;
+
+    /// Parallel version of [`central_moment`](#tymethod.central_moment), see
+    /// [`par_central_moments`](#tymethod.par_central_moments) for more details.
+    ///
+    /// **Requires crate feature `rayon`.**
+    #[cfg(feature = "rayon")]
+    fn par_central_moment(&self, order: u16) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync;
+
+    /// Parallel version of [`central_moments`](#tymethod.central_moments).
+    ///
+    /// The array is recursively split into blocks: the central moments of each block are
+    /// computed on the `rayon` thread pool with the sequential algorithm and then combined
+    /// with [`MomentsAccumulator::merge`]. Results for floating point elements can differ from
+    /// the sequential version within the tolerance documented in the
+    /// [crate-level documentation](index.html#crate-features).
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements
+    /// in the array or if `order` overflows `i32`.
+    ///
+    /// **Requires crate feature `rayon`.**
+    ///
+    /// [`MomentsAccumulator::merge`]: struct.MomentsAccumulator.html#method.merge
+    #[cfg(feature = "rayon")]
+    fn par_central_moments(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Send + Sync;
+
     private_decl! {}
 }
 
+mod accumulator;
 mod means;
@@ -0,0 +1,182 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::Normal;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    kde::{
+        errors::KdeError,
+        kernels::{
+            Biweight, Cosine, Epanechnikov, Gaussian, Kernel, Triangular, Triweight, Uniform,
+        },
+        Bandwidth,
+    },
+    KernelDensityExt,
+};
+
+/// Integrates `f` over `[-range, range]` with the trapezoidal rule.
+fn integrate(f: impl Fn(f64) -> f64, range: f64) -> f64 {
+    let n_steps = 4_001;
+    let step = 2. * range / n_steps as f64;
+    (0..=n_steps)
+        .map(|i| {
+            let weight = if i == 0 || i == n_steps { 0.5 } else { 1. };
+            weight * f(-range + i as f64 * step)
+        })
+        .sum::<f64>()
+        * step
+}
+
+fn check_kernel<K: Kernel>(kernel: K) {
+    assert_abs_diff_eq!(integrate(|u| kernel.evaluate(u), 10.), 1., epsilon = 1e-3);
+    for &u in &[0., 0.3, 0.9, 1.5] {
+        assert_eq!(kernel.evaluate(u), kernel.evaluate(-u));
+        assert!(kernel.evaluate(u) >= 0.);
+    }
+}
+
+#[test]
+fn test_kernels_are_normalized_and_symmetric() {
+    check_kernel(Gaussian);
+    check_kernel(Epanechnikov);
+    check_kernel(Uniform);
+    check_kernel(Triangular);
+    check_kernel(Biweight);
+    check_kernel(Triweight);
+    check_kernel(Cosine);
+}
+
+#[test]
+fn test_rules_of_thumb() {
+    let observations = array![[0., 10.], [1., 14.], [3., 11.], [4., 13.], [7., 12.]];
+    let std = observations.std_axis(Axis(0), 1.);
+    let (n, d): (f64, f64) = (5., 2.);
+
+    let kde = observations
+        .kernel_density(Gaussian, Bandwidth::Scott)
+        .unwrap();
+    let expected = &std * n.powf(-1. / (d + 4.));
+    assert_abs_diff_eq!(kde.bandwidth(), expected.view(), epsilon = 1e-12);
+
+    let kde = observations
+        .kernel_density(Gaussian, Bandwidth::Silverman)
+        .unwrap();
+    let expected = &std * (n * (d + 2.) / 4.).powf(-1. / (d + 4.));
+    assert_abs_diff_eq!(kde.bandwidth(), expected.view(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_density_integrates_to_one() {
+    let observations: Array1<f64> = Array::random(200, Normal::new(1., 2.).unwrap());
+    for bandwidth in [Bandwidth::Scott, Bandwidth::Silverman] {
+        let kde = observations
+            .kernel_density(Epanechnikov, bandwidth.clone())
+            .unwrap();
+        let integral = integrate(|x| kde.evaluate(&array![x + 1.]), 20.);
+        assert_abs_diff_eq!(integral, 1., epsilon = 1e-4);
+    }
+
+    let observations: Array2<f64> = Array::random((100, 2), Normal::new(0., 1.).unwrap());
+    let kde = observations
+        .kernel_density(Gaussian, Bandwidth::Scott)
+        .unwrap();
+    let step = 0.1;
+    let grid = Array::range(-6., 6., step);
+    let points: Array2<f64> = Array::from_shape_fn((grid.len() * grid.len(), 2), |(i, j)| {
+        if j == 0 {
+            grid[i / grid.len()]
+        } else {
+            grid[i % grid.len()]
+        }
+    });
+    let integral = kde.evaluate_points(&points).sum() * step * step;
+    assert_abs_diff_eq!(integral, 1., epsilon = 1e-3);
+}
+
+#[test]
+fn test_one_dimensional_observations() {
+    let observations = array![-1., 0.5, 0.7, 2.];
+    let kde = observations
+        .kernel_density(Triangular, Bandwidth::Constant(1.))
+        .unwrap();
+    let column = observations.view().insert_axis(Axis(1));
+    let kde_2d = column
+        .kernel_density(Triangular, Bandwidth::Constant(1.))
+        .unwrap();
+    assert_eq!(kde.observations(), kde_2d.observations());
+    let points = array![[-3.], [0.], [0.6], [1.9]];
+    assert_eq!(
+        kde.evaluate_points(&points),
+        kde_2d.evaluate_points(&points)
+    );
+    assert_abs_diff_eq!(kde.evaluate(&array![0.6]), (0.9 + 0.9) / 4.);
+    assert_eq!(kde.evaluate(&array![-3.]), 0.);
+}
+
+#[test]
+fn test_per_axis_bandwidth() {
+    let observations = array![[0., 0.]];
+    let kde = observations
+        .kernel_density(Uniform, Bandwidth::PerAxis(array![1., 4.]))
+        .unwrap();
+    assert_eq!(kde.evaluate(&array![0.5, 3.]), 1. / 16.);
+    assert_eq!(kde.evaluate(&array![2., 3.]), 0.);
+}
+
+#[test]
+fn test_likelihood_cross_validation() {
+    let mut observations: Array1<f64> = Array::random(100, Normal::new(0., 1.).unwrap());
+    observations
+        .slice_mut(ndarray::s![..50])
+        .mapv_inplace(|x| x + 10.);
+    let std = observations.std(1.);
+    let kde = observations
+        .kernel_density(
+            Gaussian,
+            Bandwidth::LikelihoodCrossValidation(vec![1e-3, 0.1, 100.]),
+        )
+        .unwrap();
+    assert_abs_diff_eq!(kde.bandwidth()[0], 0.1 * std, epsilon = 1e-12);
+
+    // With a compact kernel and isolated observations, every candidate gives a null
+    // leave-one-out density to some observation
+    let observations = array![0., 1., 100.];
+    let result = observations.kernel_density(
+        Epanechnikov,
+        Bandwidth::LikelihoodCrossValidation(vec![0.01, 0.1]),
+    );
+    assert_eq!(result.unwrap_err(), KdeError::InvalidBandwidth);
+}
+
+#[test]
+fn test_errors() {
+    let empty = Array2::<f64>::zeros((0, 2));
+    let result = empty.kernel_density(Gaussian, Bandwidth::Constant(1.));
+    assert!(result.unwrap_err().is_empty_input());
+
+    let single = array![[1., 2.]];
+    assert!(single
+        .kernel_density(Gaussian, Bandwidth::Constant(1.))
+        .is_ok());
+    let result = single.kernel_density(Gaussian, Bandwidth::Scott);
+    assert!(result.unwrap_err().is_empty_input());
+
+    let observations = array![[1., 2.], [2., 2.]];
+    let result = observations.kernel_density(Gaussian, Bandwidth::PerAxis(array![1.]));
+    assert!(result.unwrap_err().is_shape_mismatch());
+    let result = observations.kernel_density(Gaussian, Bandwidth::Silverman);
+    assert!(result.unwrap_err().is_invalid_bandwidth());
+    let result = observations.kernel_density(Gaussian, Bandwidth::Constant(-1.));
+    assert!(result.unwrap_err().is_invalid_bandwidth());
+    let result =
+        observations.kernel_density(Gaussian, Bandwidth::LikelihoodCrossValidation(vec![]));
+    assert!(result.unwrap_err().is_invalid_bandwidth());
+}
+
+#[test]
+#[should_panic]
+fn test_evaluate_panics_on_dimension_mismatch() {
+    let kde = array![[1., 2.]]
+        .kernel_density(Gaussian, Bandwidth::Constant(1.))
+        .unwrap();
+    kde.evaluate(&array![1.]);
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "rayon")]
+
+use approx::assert_relative_eq;
+use ndarray::{array, s, Array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::{EmptyInput, MultiInputError},
+    histogram::{strategies::Sqrt, GridBuilder},
+    interpolate::Linear,
+    CorrelationExt, DeviationExt, HistogramExt, QuantileExt, SummaryStatisticsExt,
+};
+use noisy_float::types::{n64, N64};
+
+// Large enough to be split in several blocks.
+const N: usize = 100_000;
+
+// Parallel floating point reductions are not bit-for-bit identical to the sequential ones:
+// their relative difference is of the order of `log2(N) * f64::EPSILON`, well below the
+// `max_relative = 1e-10` used throughout this file. Results close to zero, like odd central
+// moments, are compared with an absolute tolerance scaled by the magnitude of the data
+// instead, i.e. `1e-12 * 20^p` for the `p`-th central moment of values in `[-10, 30)`.
+
+#[test]
+fn test_par_summary_statistics_with_empty_array() {
+    let a: Array1<f64> = array![];
+    assert_eq!(a.par_mean(), Err(EmptyInput));
+    assert_eq!(a.par_central_moments(4), Err(EmptyInput));
+    assert_eq!(a.par_skewness(), Err(EmptyInput));
+    assert_eq!(a.par_kurtosis(), Err(EmptyInput));
+}
+
+#[test]
+fn test_par_summary_statistics_match_sequential() {
+    let a = Array::random((N / 100, 100), Uniform::new(-10., 30.).unwrap());
+    // Non-contiguous views are supported as well
+    let b = a.slice(s![..;3, 1..;2]);
+    for view in [a.view(), b] {
+        assert_relative_eq!(
+            view.par_mean().unwrap(),
+            view.mean().unwrap(),
+            max_relative = 1e-10
+        );
+        let sequential = view.central_moments(6).unwrap();
+        let parallel = view.par_central_moments(6).unwrap();
+        // Odd central moments are close to 0: rounding errors are compared to the scale of the
+        // `order`-th power of the deviations instead
+        for (order, (p, s)) in parallel.iter().zip(&sequential).enumerate() {
+            let epsilon = 1e-12 * 20_f64.powi(order as i32);
+            assert_relative_eq!(p, s, epsilon = epsilon, max_relative = 1e-10);
+        }
+        assert_relative_eq!(
+            view.par_central_moment(5).unwrap(),
+            sequential[5],
+            epsilon = 1e-12 * 20_f64.powi(5),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            view.par_skewness().unwrap(),
+            view.skewness().unwrap(),
+            epsilon = 1e-12,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            view.par_kurtosis().unwrap(),
+            view.kurtosis().unwrap(),
+            max_relative = 1e-10
+        );
+    }
+}
+
+#[test]
+fn test_par_deviation_matches_sequential() {
+    let a = Array::random((N / 100, 100), Uniform::new(-10., 10.).unwrap());
+    let mut b = Array::random((N / 100, 100), Uniform::new(-10., 10.).unwrap());
+    b.slice_mut(s![..10, ..]).assign(&a.slice(s![..10, ..]));
+
+    assert_eq!(a.par_count_eq(&b), a.count_eq(&b));
+    assert_eq!(a.par_count_neq(&b), a.count_neq(&b));
+    assert_relative_eq!(
+        a.par_sq_l2_dist(&b).unwrap(),
+        a.sq_l2_dist(&b).unwrap(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        a.par_l2_dist(&b).unwrap(),
+        a.l2_dist(&b).unwrap(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        a.par_l1_dist(&b).unwrap(),
+        a.l1_dist(&b).unwrap(),
+        max_relative = 1e-10
+    );
+    assert_eq!(a.par_linf_dist(&b), a.linf_dist(&b));
+    assert_relative_eq!(
+        a.par_mean_abs_err(&b).unwrap(),
+        a.mean_abs_err(&b).unwrap(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        a.par_mean_sq_err(&b).unwrap(),
+        a.mean_sq_err(&b).unwrap(),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        a.par_root_mean_sq_err(&b).unwrap(),
+        a.root_mean_sq_err(&b).unwrap(),
+        max_relative = 1e-10
+    );
+}
+
+#[test]
+fn test_par_deviation_errors() {
+    let a: Array2<f64> = Array2::zeros((0, 3));
+    assert_eq!(a.par_sq_l2_dist(&a), Err(MultiInputError::EmptyInput));
+    let b = Array2::<f64>::zeros((2, 3));
+    let c = Array2::<f64>::zeros((3, 2));
+    assert!(matches!(
+        b.par_l1_dist(&c),
+        Err(MultiInputError::ShapeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_par_cov_matches_sequential() {
+    let a = Array::random((20, N / 20), Uniform::new(-1., 1.).unwrap());
+    for &ddof in &[0., 1.] {
+        let sequential = a.cov(ddof).unwrap();
+        let parallel = a.par_cov(ddof).unwrap();
+        assert_eq!(parallel.shape(), sequential.shape());
+        for (p, s) in parallel.iter().zip(&sequential) {
+            assert_relative_eq!(p, s, epsilon = 1e-12, max_relative = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_par_cov_empty() {
+    let a = Array2::<f64>::zeros((2, 0));
+    assert_eq!(a.par_cov(-1.), Err(EmptyInput));
+    let a = Array2::<f64>::zeros((0, 2));
+    assert_eq!(a.par_cov(1.).unwrap().shape(), &[0, 0]);
+}
+
+#[test]
+fn test_par_histogram_matches_sequential() {
+    let observations = Array::random((N, 2), Uniform::new(-100., 100.).unwrap()).mapv(n64);
+    let grid = GridBuilder::<Sqrt<N64>>::from_array(&observations.slice(s![..N / 2, ..]))
+        .unwrap()
+        .build();
+    let sequential = observations.histogram(grid.clone());
+    let parallel = observations.par_histogram(grid);
+    assert_eq!(parallel.counts(), sequential.counts());
+    assert_eq!(parallel.grid(), sequential.grid());
+}
+
+#[test]
+fn test_par_quantiles_axis_mut_matches_sequential() {
+    let a = Array::random((50, 200), Uniform::new(0, 1000).unwrap());
+    let qs = array![n64(0.), n64(0.1), n64(0.5), n64(0.99), n64(1.)];
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let sequential = a.clone().quantiles_axis_mut(axis, &qs, &Linear).unwrap();
+        let parallel = a
+            .clone()
+            .par_quantiles_axis_mut(axis, &qs, &Linear)
+            .unwrap();
+        assert_eq!(parallel, sequential);
+        assert_eq!(
+            a.clone().par_quantile_axis_mut(axis, n64(0.3), &Linear),
+            a.clone().quantile_axis_mut(axis, n64(0.3), &Linear)
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array, Array1, Array2, Axis};
+use ndarray_rand::rand_distr::{Normal, Uniform};
+use ndarray_rand::RandomExt;
+use ndarray_stats::{
+    errors::EmptyInput,
+    interpolate::{Linear, Midpoint, Nearest},
+    robust::Scaling,
+    RobustStatisticsExt,
+};
+use noisy_float::types::{n64, N64};
+
+#[test]
+fn test_mad() {
+    let a = array![1, 1, 2, 2, 4, 6, 9];
+    assert_eq!(a.mad(Scaling::Raw, &Nearest), Ok(1));
+    let a = array![1., 2., 3., 4.].mapv(n64);
+    // median 2.5, deviations 1.5, 0.5, 0.5, 1.5
+    assert_eq!(a.mad(Scaling::Raw, &Midpoint), Ok(n64(1.)));
+    assert_abs_diff_eq!(
+        a.mad(Scaling::Normal, &Midpoint).unwrap().raw(),
+        1.482_602_218_505_602,
+        epsilon = 1e-15
+    );
+}
+
+#[test]
+fn test_iqr() {
+    let a = Array::range(1., 10., 1.).mapv(n64);
+    assert_eq!(a.iqr(Scaling::Raw, &Linear), Ok(n64(4.)));
+    assert_abs_diff_eq!(
+        a.iqr(Scaling::Normal, &Linear).unwrap().raw(),
+        4. / 1.348_979_500_392_163_4,
+        epsilon = 1e-15
+    );
+    let a = array![3, 1, 2, 4];
+    // quartiles at indices 0.75 and 2.25, i.e. 2 and 3
+    assert_eq!(a.iqr(Scaling::Raw, &Nearest), Ok(1));
+}
+
+#[test]
+fn test_qn_and_sn() {
+    let a = array![1, 2, 3, 5, 100];
+    assert_eq!(a.qn(Scaling::Raw), Ok(2));
+    assert_eq!(a.sn(Scaling::Raw), Ok(2));
+    let single = array![n64(7.)];
+    assert_eq!(single.qn(Scaling::Raw), Ok(n64(0.)));
+    assert_eq!(single.sn(Scaling::Normal), Ok(n64(0.)));
+}
+
+/// Brute-force Qn: sorts all the pairwise distances.
+fn naive_qn(values: &[i64]) -> i64 {
+    let n = values.len();
+    let mut distances: Vec<i64> = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (values[i] - values[j]).abs()))
+        .collect();
+    distances.sort_unstable();
+    let h = n / 2 + 1;
+    distances[h * (h - 1) / 2 - 1]
+}
+
+/// Brute-force Sn: sorts the distances to each value, then the high medians.
+fn naive_sn(values: &[i64]) -> i64 {
+    let n = values.len();
+    let mut high_medians: Vec<i64> = values
+        .iter()
+        .map(|x_i| {
+            let mut distances: Vec<i64> = values.iter().map(|x_j| (x_i - x_j).abs()).collect();
+            distances.sort_unstable();
+            distances[n / 2]
+        })
+        .collect();
+    high_medians.sort_unstable();
+    high_medians[(n + 1) / 2 - 1]
+}
+
+#[test]
+fn test_qn_and_sn_against_brute_force() {
+    let values: Vec<i64> = (0..40).map(|i: i64| (i * i * 37 + 11) % 101 - 50).collect();
+    for n in 2..values.len() {
+        let a = Array1::from(values[..n].to_vec());
+        assert_eq!(a.qn(Scaling::Raw), Ok(naive_qn(&values[..n])));
+        assert_eq!(a.sn(Scaling::Raw), Ok(naive_sn(&values[..n])));
+    }
+}
+
+#[test]
+fn test_qn_against_brute_force_with_ties() {
+    for &(n, high) in &[(50, 3), (101, 10), (300, 1_000), (257, 1_000_000)] {
+        let a: Array1<i64> = Array::random(n, Uniform::new(0, high).unwrap());
+        assert_eq!(
+            a.qn(Scaling::Raw),
+            Ok(naive_qn(a.as_slice().unwrap())),
+            "{:?}",
+            a
+        );
+    }
+}
+
+#[test]
+fn test_qn_of_large_inputs() {
+    // Storing all the pairwise distances would take 40 GB
+    let a: Array1<f64> = Array::random(100_000, Normal::new(3., 2.).unwrap());
+    let qn = a.mapv(n64).qn(Scaling::Normal).unwrap();
+    assert_abs_diff_eq!(qn.raw(), 2., epsilon = 0.05);
+}
+
+#[test]
+fn test_normal_scaling_estimates_the_standard_deviation() {
+    let a: Array1<f64> = Array::random(2_000, Normal::new(3., 2.).unwrap());
+    let a = a.mapv(n64);
+    let estimates = [
+        a.mad(Scaling::Normal, &Linear).unwrap(),
+        a.iqr(Scaling::Normal, &Linear).unwrap(),
+        a.qn(Scaling::Normal).unwrap(),
+        a.sn(Scaling::Normal).unwrap(),
+    ];
+    for estimate in estimates {
+        assert_abs_diff_eq!(estimate.raw(), 2., epsilon = 0.3);
+    }
+}
+
+#[test]
+fn test_axis_matches_lanes() {
+    let a: Array2<N64> = Array::random((7, 4), Normal::new(0., 1.).unwrap()).mapv(n64);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let mad = a.mad_axis(axis, Scaling::Normal, &Midpoint).unwrap();
+        let iqr = a.iqr_axis(axis, Scaling::Raw, &Linear).unwrap();
+        let qn = a.qn_axis(axis, Scaling::Raw).unwrap();
+        let sn = a.sn_axis(axis, Scaling::Normal).unwrap();
+        for (i, lane) in a.axis_iter(Axis(1 - axis.index())).enumerate() {
+            assert_eq!(mad[i], lane.mad(Scaling::Normal, &Midpoint).unwrap());
+            assert_eq!(iqr[i], lane.iqr(Scaling::Raw, &Linear).unwrap());
+            assert_eq!(qn[i], lane.qn(Scaling::Raw).unwrap());
+            assert_eq!(sn[i], lane.sn(Scaling::Normal).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_skipnan() {
+    let a = array![1., f64::NAN, 1., 2., 2., 4., f64::NAN, 6., 9.];
+    assert_eq!(a.mad_skipnan(Scaling::Raw, &Nearest), Ok(1.));
+    assert_eq!(a.iqr_skipnan(Scaling::Raw, &Nearest), Ok(4.));
+    let b = array![1., 2., f64::NAN, 3., 5., 100.];
+    assert_eq!(b.qn_skipnan(Scaling::Raw), Ok(2.));
+    assert_eq!(b.sn_skipnan(Scaling::Raw), Ok(2.));
+
+    let nan = array![f64::NAN, f64::NAN];
+    assert!(nan.mad_skipnan(Scaling::Raw, &Linear).unwrap().is_nan());
+    assert!(nan.qn_skipnan(Scaling::Normal).unwrap().is_nan());
+
+    let c = array![[1., f64::NAN], [3., f64::NAN], [f64::NAN, f64::NAN]];
+    let iqr = c.iqr_axis_skipnan(Axis(0), Scaling::Raw, &Linear).unwrap();
+    assert_eq!(iqr[0], 1.);
+    assert!(iqr[1].is_nan());
+    let mad = c.mad_axis_skipnan(Axis(1), Scaling::Raw, &Linear).unwrap();
+    assert_eq!(mad[0], 0.);
+    assert!(mad[2].is_nan());
+    let qn = c.qn_axis_skipnan(Axis(0), Scaling::Raw).unwrap();
+    assert_eq!(qn[0], 2.);
+    let sn = c.sn_axis_skipnan(Axis(0), Scaling::Raw).unwrap();
+    assert_eq!(sn[0], 2.);
+}
+
+#[test]
+fn test_empty_input() {
+    let a = Array1::<N64>::zeros(0);
+    assert_eq!(a.mad(Scaling::Raw, &Linear), Err(EmptyInput));
+    assert_eq!(a.iqr(Scaling::Raw, &Linear), Err(EmptyInput));
+    assert_eq!(a.qn(Scaling::Raw), Err(EmptyInput));
+    assert_eq!(a.sn(Scaling::Raw), Err(EmptyInput));
+    assert_eq!(
+        Array1::<f64>::zeros(0).mad_skipnan(Scaling::Raw, &Linear),
+        Err(EmptyInput)
+    );
+
+    let b = Array2::<N64>::zeros((0, 3));
+    assert_eq!(b.mad_axis(Axis(0), Scaling::Raw, &Linear), Err(EmptyInput));
+    assert_eq!(b.qn_axis(Axis(0), Scaling::Raw), Err(EmptyInput));
+    assert_eq!(b.sn_axis(Axis(1), Scaling::Raw).unwrap().len(), 0);
+    assert_eq!(
+        Array2::<f64>::zeros((3, 0)).iqr_axis_skipnan(Axis(1), Scaling::Raw, &Linear),
+        Err(EmptyInput)
+    );
+}
+
+/// Sorts `values`, then trims or winsorizes `g = ⌊proportion · n⌋` elements on each tail.
+fn naive_trim_and_winsorize(values: &[N64], proportion: f64) -> (Vec<N64>, Vec<N64>) {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len();
+    let g = (proportion * n as f64).floor() as usize;
+    let trimmed = sorted[g..n - g].to_vec();
+    let winsorized = sorted
+        .iter()
+        .map(|&x| x.clamp(sorted[g], sorted[n - 1 - g]))
+        .collect();
+    (trimmed, winsorized)
+}
+
+#[test]
+fn test_trimmed_and_winsorized_statistics() {
+    let a = array![5, 5, 9, 5, 1, 5];
+    assert_eq!(a.trimmed_mean(0.2), Ok(5));
+    assert_eq!(a.winsorized_mean(0.2), Ok(5));
+    // Down to the median
+    let b = array![3., 100., 1., 2., -7.].mapv(n64);
+    assert_eq!(b.trimmed_mean(0.49), Ok(n64(2.)));
+    assert_eq!(b.winsorized_mean(0.49), Ok(n64(2.)));
+    assert_eq!(b.winsorized_var(0.49, n64(0.)), Ok(n64(0.)));
+    // No trimming
+    assert_eq!(b.trimmed_mean(0.1), Ok(n64(99. / 5.)));
+    assert_eq!(b.winsorized_mean(0.), b.mean().ok_or(EmptyInput));
+    assert_abs_diff_eq!(
+        b.winsorized_var(0., n64(1.)).unwrap().raw(),
+        b.var(n64(1.)).raw(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_trimmed_and_winsorized_statistics_against_brute_force() {
+    let a: Array1<f64> = Array::random(101, Normal::new(0., 10.).unwrap());
+    let a = a.mapv(n64);
+    for &proportion in &[0., 0.05, 0.1, 0.25, 0.3, 0.45] {
+        let (trimmed, winsorized) = naive_trim_and_winsorize(a.as_slice().unwrap(), proportion);
+        let trimmed = Array1::from(trimmed);
+        let winsorized = Array1::from(winsorized);
+        assert_abs_diff_eq!(
+            a.trimmed_mean(proportion).unwrap().raw(),
+            trimmed.mean().unwrap().raw(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.winsorized_mean(proportion).unwrap().raw(),
+            winsorized.mean().unwrap().raw(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.winsorized_var(proportion, n64(1.)).unwrap().raw(),
+            winsorized.var(n64(1.)).raw(),
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn test_trimmed_and_winsorized_axis_matches_lanes() {
+    let a: Array2<N64> = Array::random((9, 5), Normal::new(0., 1.).unwrap()).mapv(n64);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let trimmed_mean = a.trimmed_mean_axis(axis, 0.2).unwrap();
+        let winsorized_mean = a.winsorized_mean_axis(axis, 0.2).unwrap();
+        let winsorized_var = a.winsorized_var_axis(axis, 0.2, n64(1.)).unwrap();
+        for (i, lane) in a.axis_iter(Axis(1 - axis.index())).enumerate() {
+            // Quickselect shuffles the elements, hence the summation order
+            assert_abs_diff_eq!(
+                trimmed_mean[i].raw(),
+                lane.trimmed_mean(0.2).unwrap().raw(),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                winsorized_mean[i].raw(),
+                lane.winsorized_mean(0.2).unwrap().raw(),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                winsorized_var[i].raw(),
+                lane.winsorized_var(0.2, n64(1.)).unwrap().raw(),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn test_trimmed_and_winsorized_empty_input() {
+    let a = Array1::<N64>::zeros(0);
+    assert_eq!(a.trimmed_mean(0.1), Err(EmptyInput));
+    assert_eq!(a.winsorized_mean(0.1), Err(EmptyInput));
+    assert_eq!(a.winsorized_var(0.1, n64(0.)), Err(EmptyInput));
+    let b = Array2::<N64>::zeros((0, 2));
+    assert_eq!(b.trimmed_mean_axis(Axis(0), 0.1), Err(EmptyInput));
+    assert_eq!(b.winsorized_mean_axis(Axis(0), 0.1), Err(EmptyInput));
+    assert_eq!(
+        b.winsorized_var_axis(Axis(0), 0.1, n64(0.)),
+        Err(EmptyInput)
+    );
+}
+
+#[test]
+#[should_panic(expected = "`proportion` needs to be in [0, 0.5).")]
+fn test_trimmed_mean_panics_on_half_proportion() {
+    let _ = array![1, 2, 3, 4].trimmed_mean(0.5);
+}
+
+#[test]
+#[should_panic(expected = "`proportion` needs to be in [0, 0.5).")]
+fn test_winsorized_mean_panics_on_negative_proportion() {
+    let _ = Array1::<i32>::zeros(0).winsorized_mean(-0.1);
+}
+
+#[test]
+#[should_panic(expected = "`ddof` must not be less than zero")]
+fn test_winsorized_var_panics_on_negative_ddof() {
+    let _ = array![1., 2., 3.].mapv(n64).winsorized_var(0.1, n64(-1.));
+}
+
+#[test]
+#[should_panic(expected = "unexpected NaN")]
+fn test_winsorized_var_without_degrees_of_freedom_is_nan() {
+    // `N64` cannot represent the NaN result
+    let _ = array![1., 2., 3.].mapv(n64).winsorized_var(0.1, n64(3.));
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "serde")]
+
+use ndarray::array;
+use ndarray_stats::{
+    errors::{MinMaxError, MultiInputError, QuantileError, ShapeMismatch},
+    histogram::{errors::BinsBuildError, Bins, Edges, Grid, Histogram, WeightedHistogram},
+    HistogramExt,
+};
+use noisy_float::types::n64;
+
+fn grid() -> Grid<i32> {
+    Grid::from(vec![
+        Bins::new(Edges::from(vec![0, 2, 4, 6])),
+        Bins::new(Edges::from(vec![-1, 1])),
+    ])
+}
+
+#[test]
+fn test_edges_roundtrip() {
+    let edges = Edges::from(vec![n64(1.5), n64(-2.), n64(0.), n64(1.5)]);
+    let json = serde_json::to_string(&edges).unwrap();
+    let deserialized: Edges<_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, edges);
+}
+
+#[test]
+fn test_edges_deserialization_rejects_unsorted_edges() {
+    let result = serde_json::from_str::<Edges<i32>>(r#"{"edges":[0,3,2]}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_edges_deserialization_rejects_duplicated_edges() {
+    let result = serde_json::from_str::<Edges<i32>>(r#"{"edges":[0,2,2,3]}"#);
+    assert!(result.is_err());
+    let result = serde_json::from_str::<Bins<i32>>(r#"{"edges":{"edges":[1,1]}}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bins_uniform_lookup_is_not_serialized() {
+    let bins = Bins::with_uniform_lookup(Edges::from(vec![0, 2, 4, 6]));
+    assert!(bins.is_uniform());
+    let json = serde_json::to_string(&bins).unwrap();
+    let deserialized: Bins<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, bins);
+    assert!(!deserialized.is_uniform());
+    assert!(deserialized.into_uniform_lookup().is_uniform());
+}
+
+#[test]
+fn test_grid_roundtrip() {
+    let grid = grid();
+    let json = serde_json::to_string(&grid).unwrap();
+    let deserialized: Grid<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, grid);
+}
+
+#[test]
+fn test_histogram_can_be_filled_after_roundtrip() {
+    let observations = array![[0, 0], [3, -1], [5, 0], [5, 0]];
+    let histogram = observations.histogram(grid());
+
+    let json = serde_json::to_string(&histogram).unwrap();
+    let mut deserialized: Histogram<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.counts(), histogram.counts());
+    assert_eq!(deserialized.grid(), histogram.grid());
+
+    deserialized.add_observation(&array![1, 0]).unwrap();
+    let expected = array![[2], [1], [2]];
+    assert_eq!(deserialized.counts(), expected.into_dyn());
+}
+
+#[test]
+fn test_histogram_flow_counts_roundtrip() {
+    let observations = array![[0, 0], [-3, -1], [7, 0], [5, 5]];
+    let histogram = observations.histogram_with_flow(grid());
+
+    let json = serde_json::to_string(&histogram).unwrap();
+    let deserialized: Histogram<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.flow(), histogram.flow());
+    assert_eq!(deserialized.entries(), 4);
+
+    // Histograms serialized without flow counts can still be deserialized
+    let mut value = serde_json::to_value(&histogram).unwrap();
+    value.as_object_mut().unwrap().remove("flow");
+    let deserialized: Histogram<i32> = serde_json::from_value(value).unwrap();
+    assert!(deserialized.flow().is_none());
+}
+
+#[test]
+fn test_weighted_histogram_roundtrip() {
+    let observations = array![[0, 0], [3, -1], [5, 0]];
+    let histogram = observations
+        .weighted_histogram(grid(), &array![0.5, 1., 2.])
+        .unwrap();
+
+    let json = serde_json::to_string(&histogram).unwrap();
+    let deserialized: WeightedHistogram<i32, f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.sum_of_weights(), histogram.sum_of_weights());
+    assert_eq!(
+        deserialized.sum_of_squared_weights(),
+        histogram.sum_of_squared_weights()
+    );
+    assert_eq!(deserialized.grid(), histogram.grid());
+
+    let mut value = serde_json::to_value(&histogram).unwrap();
+    value["sum_of_weights"] = serde_json::to_value(array![0., 1.].into_dyn()).unwrap();
+    assert!(serde_json::from_value::<WeightedHistogram<i32, f64>>(value).is_err());
+}
+
+#[test]
+fn test_histogram_deserialization_rejects_mismatched_counts() {
+    let histogram = Histogram::new(grid());
+    let mut value = serde_json::to_value(&histogram).unwrap();
+    value["counts"] = serde_json::to_value(array![[0, 0], [0, 0], [0, 0]].into_dyn()).unwrap();
+    let result = serde_json::from_value::<Histogram<i32>>(value);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_errors_roundtrip() {
+    let error = MultiInputError::ShapeMismatch(ShapeMismatch {
+        first_shape: vec![2, 3],
+        second_shape: vec![3],
+    });
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MultiInputError>(&json).unwrap(),
+        error
+    );
+
+    let error = QuantileError::InvalidQuantile(n64(1.5));
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(serde_json::from_str::<QuantileError>(&json).unwrap(), error);
+
+    let error = MinMaxError::UndefinedOrder;
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(serde_json::from_str::<MinMaxError>(&json).unwrap(), error);
+
+    let json = serde_json::to_string(&BinsBuildError::Strategy).unwrap();
+    assert!(serde_json::from_str::<BinsBuildError>(&json)
+        .unwrap()
+        .is_strategy());
+}
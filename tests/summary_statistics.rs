@@ -4,7 +4,7 @@ use ndarray_rand::rand_distr::Uniform;
 use ndarray_rand::RandomExt;
 use ndarray_stats::{
     errors::{EmptyInput, MultiInputError, ShapeMismatch},
-    SummaryStatisticsExt,
+    MomentsAccumulator, SummaryStatisticsExt,
 };
 use noisy_float::types::N64;
 use quickcheck::{quickcheck, TestResult};
@@ -412,3 +412,242 @@ fn test_kurtosis_and_skewness() {
     assert_abs_diff_eq!(kurtosis, expected_kurtosis, epsilon = 1e-12);
     assert_abs_diff_eq!(skewness, expected_skewness, epsilon = 1e-8);
 }
+
+#[test]
+fn test_moments_accumulator_with_no_observations() {
+    let mut accumulator = MomentsAccumulator::<f64>::new(4);
+    accumulator.push_array(&Array1::<f64>::zeros(0));
+    accumulator.merge(&MomentsAccumulator::new(4));
+    assert_eq!(accumulator.count(), 0);
+    assert_eq!(accumulator.mean(), Err(EmptyInput));
+    assert_eq!(accumulator.central_moments(), Err(EmptyInput));
+    assert_eq!(accumulator.var(0.), Err(EmptyInput));
+    assert_eq!(accumulator.skewness(), Err(EmptyInput));
+    assert_eq!(accumulator.kurtosis(), Err(EmptyInput));
+}
+
+#[test]
+fn test_moments_accumulator_single_chunk_matches_central_moments() {
+    let a = Array::random(100, Uniform::new(-50., 50.).unwrap());
+    let order = 8;
+    let mut accumulator = MomentsAccumulator::new(order);
+    accumulator.push_array(&a);
+    assert_eq!(accumulator.count(), a.len());
+    assert_eq!(accumulator.mean().unwrap(), a.mean().unwrap());
+    assert_eq!(
+        accumulator.central_moments().unwrap(),
+        a.central_moments(order).unwrap()
+    );
+    assert_eq!(accumulator.skewness().unwrap(), a.skewness().unwrap());
+    assert_eq!(accumulator.kurtosis().unwrap(), a.kurtosis().unwrap());
+}
+
+#[test]
+fn test_moments_accumulator_chunked_and_merged() {
+    let a = Array::random(1000, Uniform::new(-50., 50.).unwrap());
+    let order = 6;
+    let expected = a.central_moments(order).unwrap();
+
+    // Unequal chunks, some of them empty, merged pairwise.
+    let boundaries = [0, 1, 1, 17, 250, 251, 600, 999, 1000];
+    let mut partials: Vec<MomentsAccumulator<f64>> = boundaries
+        .windows(2)
+        .map(|w| {
+            let mut accumulator = MomentsAccumulator::new(order);
+            accumulator.push_array(&a.slice(ndarray::s![w[0]..w[1]]));
+            accumulator
+        })
+        .collect();
+    while partials.len() > 1 {
+        let right = partials.pop().unwrap();
+        let mut left = partials.pop().unwrap();
+        left.merge(&right);
+        partials.insert(0, left);
+    }
+    let merged = partials.pop().unwrap();
+
+    // One observation at a time.
+    let mut streamed = MomentsAccumulator::new(order);
+    for &x in a.iter() {
+        streamed.push(x);
+    }
+
+    for accumulator in &[merged, streamed] {
+        assert_eq!(accumulator.count(), a.len());
+        assert_abs_diff_eq!(
+            accumulator.mean().unwrap(),
+            a.mean().unwrap(),
+            epsilon = 1e-9
+        );
+        let moments = accumulator.central_moments().unwrap();
+        for (moment, expected_moment) in moments.iter().zip(&expected) {
+            assert_abs_diff_eq!(
+                moment,
+                expected_moment,
+                epsilon = 1e-8 * expected_moment.abs().max(1.)
+            );
+        }
+        assert_abs_diff_eq!(
+            accumulator.var(1.).unwrap(),
+            a.var(1.),
+            epsilon = 1e-9 * a.var(1.)
+        );
+        assert_abs_diff_eq!(
+            accumulator.skewness().unwrap(),
+            a.skewness().unwrap(),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            accumulator.kurtosis().unwrap(),
+            a.kurtosis().unwrap(),
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_moments_accumulator_merge_different_orders() {
+    let mut accumulator = MomentsAccumulator::<f64>::new(4);
+    accumulator.push(1.);
+    accumulator.merge(&MomentsAccumulator::new(3));
+}
+
+#[test]
+fn test_var_std_sem() {
+    let a = array![1., 2., 3., 4.];
+    assert_eq!(SummaryStatisticsExt::var(&*a, 0.), Ok(1.25));
+    assert_abs_diff_eq!(
+        SummaryStatisticsExt::var(&*a, 1.).unwrap(),
+        5. / 3.,
+        epsilon = 1e-15
+    );
+    assert_abs_diff_eq!(
+        SummaryStatisticsExt::std(&*a, 1.).unwrap(),
+        (5_f64 / 3.).sqrt(),
+        epsilon = 1e-15
+    );
+    assert_abs_diff_eq!(
+        a.sem(1.).unwrap(),
+        (5_f64 / 3. / 4.).sqrt(),
+        epsilon = 1e-15
+    );
+
+    let a: Array2<f64> = Array::random((20, 30), Uniform::new(-5., 5.).unwrap());
+    assert_abs_diff_eq!(
+        SummaryStatisticsExt::var(&*a, 1.).unwrap(),
+        a.var(1.),
+        epsilon = 1e-12
+    );
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let n = a.len_of(axis) as f64;
+        assert_abs_diff_eq!(
+            SummaryStatisticsExt::var_axis(&*a, axis, 0.5).unwrap(),
+            a.var_axis(axis, 0.5),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            SummaryStatisticsExt::std_axis(&*a, axis, 1.).unwrap(),
+            a.std_axis(axis, 1.),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.sem_axis(axis, 1.).unwrap(),
+            a.std_axis(axis, 1.) / n.sqrt(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_var_std_sem_skipnan() {
+    let a = array![1., f64::NAN, 2., 3., 4., f64::NAN];
+    let b = array![1., 2., 3., 4.];
+    assert_eq!(a.mean_skipnan(), Ok(2.5));
+    assert_eq!(a.var_skipnan(1.), SummaryStatisticsExt::var(&*b, 1.));
+    assert_eq!(a.std_skipnan(0.), SummaryStatisticsExt::std(&*b, 0.));
+    assert_eq!(a.sem_skipnan(1.), b.sem(1.));
+    // Not enough degrees of freedom
+    assert!(a.var_skipnan(4.).unwrap().is_nan());
+    assert!(a.sem_skipnan(5.).unwrap().is_nan());
+
+    let nan = array![f64::NAN, f64::NAN];
+    assert!(nan.mean_skipnan().unwrap().is_nan());
+    assert!(nan.var_skipnan(0.).unwrap().is_nan());
+    assert!(nan.std_skipnan(0.).unwrap().is_nan());
+
+    let c = array![[1., f64::NAN, 5.], [3., f64::NAN, f64::NAN]];
+    let mean = c.mean_axis_skipnan(Axis(0)).unwrap();
+    assert_eq!(mean[0], 2.);
+    assert!(mean[1].is_nan());
+    assert_eq!(mean[2], 5.);
+    let var = c.var_axis_skipnan(Axis(0), 1.).unwrap();
+    assert_eq!(var[0], 2.);
+    assert!(var[1].is_nan());
+    assert!(var[2].is_nan());
+    assert_eq!(c.std_axis_skipnan(Axis(1), 0.), Ok(array![2., 0.]));
+    assert_eq!(
+        c.sem_axis_skipnan(Axis(1), 0.),
+        Ok(array![2_f64.sqrt(), 0.])
+    );
+}
+
+#[test]
+fn test_var_std_sem_with_empty_input() {
+    let a: Array1<f64> = array![];
+    assert_eq!(SummaryStatisticsExt::var(&*a, 0.), Err(EmptyInput));
+    assert_eq!(SummaryStatisticsExt::std(&*a, 0.), Err(EmptyInput));
+    assert_eq!(a.sem(0.), Err(EmptyInput));
+    assert_eq!(a.mean_skipnan(), Err(EmptyInput));
+    assert_eq!(a.var_skipnan(0.), Err(EmptyInput));
+    assert_eq!(a.std_skipnan(0.), Err(EmptyInput));
+    assert_eq!(a.sem_skipnan(0.), Err(EmptyInput));
+
+    let b = Array2::<f64>::zeros((0, 2));
+    assert_eq!(
+        SummaryStatisticsExt::var_axis(&*b, Axis(0), 0.),
+        Err(EmptyInput)
+    );
+    assert_eq!(
+        SummaryStatisticsExt::std_axis(&*b, Axis(0), 0.),
+        Err(EmptyInput)
+    );
+    assert_eq!(b.sem_axis(Axis(0), 0.), Err(EmptyInput));
+    assert_eq!(b.mean_axis_skipnan(Axis(0)), Err(EmptyInput));
+    assert_eq!(b.var_axis_skipnan(Axis(0), 0.), Err(EmptyInput));
+    assert_eq!(b.std_axis_skipnan(Axis(0), 0.), Err(EmptyInput));
+    assert_eq!(b.sem_axis_skipnan(Axis(0), 0.), Err(EmptyInput));
+    // Lanes along the other axis are empty, but there are none
+    assert_eq!(b.sem_axis(Axis(1), 0.).unwrap().len(), 0);
+}
+
+#[test]
+fn test_var_std_sem_without_degrees_of_freedom() {
+    let a: Array2<f64> = array![[1., 2.], [3., 4.]];
+    assert!(SummaryStatisticsExt::var(&*a, 4.).unwrap().is_nan());
+    assert!(SummaryStatisticsExt::std(&*a, 5.).unwrap().is_nan());
+    assert!(a.sem(4.).unwrap().is_nan());
+    assert!(SummaryStatisticsExt::var_axis(&*a, Axis(0), 2.)
+        .unwrap()
+        .iter()
+        .all(|x| x.is_nan()));
+    assert!(SummaryStatisticsExt::std_axis(&*a, Axis(1), 3.)
+        .unwrap()
+        .iter()
+        .all(|x| x.is_nan()));
+    assert!(a.sem_axis(Axis(0), 2.).unwrap().iter().all(|x| x.is_nan()));
+    assert_eq!(SummaryStatisticsExt::var(&*a, 3.), Ok(5.));
+}
+
+#[test]
+#[should_panic(expected = "`ddof` must not be less than zero")]
+fn test_var_panics_on_negative_ddof() {
+    let _ = SummaryStatisticsExt::var(&*array![1_f64, 2.], -1.);
+}
+
+#[test]
+#[should_panic(expected = "`ddof` must not be less than zero")]
+fn test_var_skipnan_panics_on_negative_ddof() {
+    let _ = array![1., 2.].var_axis_skipnan(Axis(0), -1.);
+}